@@ -36,6 +36,12 @@ pub struct Logging {
     pub destination: LogOutput,   
 }
 
+impl Default for Logging {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Logging {
     pub fn new() -> Self {
         Self {
@@ -5,7 +5,43 @@
 //! - Date format validation
 //! - Date formatting in different styles
 
-use chrono::{Duration, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, Utc, Weekday};
+use chrono_tz::Tz;
+use crate::string_utils::ordinal;
+use std::fmt;
+
+/// Errors returned by date functions that can fail for reasons other than parsing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    /// The input could not be parsed as a date
+    Parse(chrono::ParseError),
+    /// The result falls outside the range of representable dates
+    OutOfRange,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::Parse(err) => write!(f, "invalid date: {}", err),
+            DateError::OutOfRange => write!(f, "date is out of the representable range"),
+        }
+    }
+}
+
+impl std::error::Error for DateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DateError::Parse(err) => Some(err),
+            DateError::OutOfRange => None,
+        }
+    }
+}
+
+impl From<chrono::ParseError> for DateError {
+    fn from(err: chrono::ParseError) -> Self {
+        DateError::Parse(err)
+    }
+}
 
 /// Calculates the difference between two dates in days
 /// 
@@ -143,6 +179,34 @@ pub fn add_days(date_str: &str, days: i64) -> Result<String, chrono::ParseError>
     Ok(new_date.format("%Y-%m-%d").to_string())
 }
 
+/// Adds days to a date, reporting an error instead of panicking if the result
+/// is out of range
+/// 
+/// `add_days` panics when the resulting date cannot be represented (roughly
+/// beyond ±262,000 years); this variant returns `DateError::OutOfRange` instead.
+/// 
+/// # Arguments
+/// * `date_str` - The input date string in YYYY-MM-DD format
+/// * `days` - The number of days to add (can be negative to subtract)
+/// 
+/// # Returns
+/// The new date string, or a `DateError` if the input is invalid or the result
+/// is out of range
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::{checked_add_days, DateError};
+/// assert_eq!(checked_add_days("2023-12-25", 7).unwrap(), "2024-01-01");
+/// assert_eq!(checked_add_days("2023-12-25", i64::MAX), Err(DateError::OutOfRange));
+/// ```
+pub fn checked_add_days(date_str: &str, days: i64) -> Result<String, DateError> {
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    let new_date = Duration::try_days(days)
+        .and_then(|duration| date.checked_add_signed(duration))
+        .ok_or(DateError::OutOfRange)?;
+    Ok(new_date.format("%Y-%m-%d").to_string())
+}
+
 /// Checks if a year is a leap year
 /// 
 /// # Arguments
@@ -181,6 +245,650 @@ pub fn day_of_week(date_str: &str) -> Result<String, chrono::ParseError> {
     Ok(date.format("%A").to_string())
 }
 
+/// Validates if a date-time string matches a specific format
+/// 
+/// Unlike `validate_date_format`, the format may include time specifiers
+/// such as `%H:%M:%S`, and the time component is validated as well.
+/// 
+/// # Arguments
+/// * `datetime_str` - The date-time string to validate
+/// * `format` - The expected format (e.g., "%Y-%m-%d %H:%M:%S")
+/// 
+/// # Returns
+/// `true` if the date-time string matches the format, `false` otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::validate_datetime_format;
+/// assert!(validate_datetime_format("2023-12-25 14:30:00", "%Y-%m-%d %H:%M:%S"));
+/// assert!(!validate_datetime_format("2023-12-25 25:30:00", "%Y-%m-%d %H:%M:%S"));
+/// ```
+pub fn validate_datetime_format(datetime_str: &str, format: &str) -> bool {
+    NaiveDateTime::parse_from_str(datetime_str, format).is_ok()
+}
+
+/// Calculates the difference between two date-times in seconds
+/// 
+/// # Arguments
+/// * `datetime1` - The first date-time in "YYYY-MM-DD HH:MM:SS" format
+/// * `datetime2` - The second date-time in "YYYY-MM-DD HH:MM:SS" format
+/// 
+/// # Returns
+/// The number of seconds between the date-times (positive if datetime1 > datetime2)
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::datetime_difference_seconds;
+/// assert_eq!(datetime_difference_seconds("2023-01-01 12:00:30", "2023-01-01 12:00:00").unwrap(), 30);
+/// ```
+pub fn datetime_difference_seconds(datetime1: &str, datetime2: &str) -> Result<i64, chrono::ParseError> {
+    let dt1 = NaiveDateTime::parse_from_str(datetime1, "%Y-%m-%d %H:%M:%S")?;
+    let dt2 = NaiveDateTime::parse_from_str(datetime2, "%Y-%m-%d %H:%M:%S")?;
+    Ok((dt1 - dt2).num_seconds())
+}
+
+/// Calculates the difference between two date-times in whole minutes
+/// 
+/// # Arguments
+/// * `datetime1` - The first date-time in "YYYY-MM-DD HH:MM:SS" format
+/// * `datetime2` - The second date-time in "YYYY-MM-DD HH:MM:SS" format
+/// 
+/// # Returns
+/// The number of whole minutes between the date-times (positive if datetime1 > datetime2)
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::datetime_difference_minutes;
+/// assert_eq!(datetime_difference_minutes("2023-01-01 12:30:00", "2023-01-01 12:00:00").unwrap(), 30);
+/// ```
+pub fn datetime_difference_minutes(datetime1: &str, datetime2: &str) -> Result<i64, chrono::ParseError> {
+    Ok(datetime_difference_seconds(datetime1, datetime2)? / 60)
+}
+
+/// Calculates the difference between two date-times in whole hours
+/// 
+/// # Arguments
+/// * `datetime1` - The first date-time in "YYYY-MM-DD HH:MM:SS" format
+/// * `datetime2` - The second date-time in "YYYY-MM-DD HH:MM:SS" format
+/// 
+/// # Returns
+/// The number of whole hours between the date-times (positive if datetime1 > datetime2)
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::datetime_difference_hours;
+/// assert_eq!(datetime_difference_hours("2023-01-02 12:00:00", "2023-01-01 12:00:00").unwrap(), 24);
+/// ```
+pub fn datetime_difference_hours(datetime1: &str, datetime2: &str) -> Result<i64, chrono::ParseError> {
+    Ok(datetime_difference_seconds(datetime1, datetime2)? / 3600)
+}
+
+/// Adds weeks to a date
+/// 
+/// # Arguments
+/// * `date_str` - The input date string in YYYY-MM-DD format
+/// * `weeks` - The number of weeks to add (can be negative to subtract)
+/// 
+/// # Returns
+/// The new date string or an error
+/// 
+/// # Panics
+/// Panics if the resulting date cannot be represented (roughly beyond
+/// ±262,000 years), like `add_days`. Use `checked_add_days` with
+/// `weeks * 7` days to get an error instead.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::add_weeks;
+/// assert_eq!(add_weeks("2023-12-25", 1).unwrap(), "2024-01-01");
+/// assert_eq!(add_weeks("2023-12-25", -2).unwrap(), "2023-12-11");
+/// ```
+pub fn add_weeks(date_str: &str, weeks: i64) -> Result<String, chrono::ParseError> {
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    let new_date = weeks
+        .checked_mul(7)
+        .and_then(Duration::try_days)
+        .and_then(|duration| date.checked_add_signed(duration))
+        .expect("date out of range");
+    Ok(new_date.format("%Y-%m-%d").to_string())
+}
+
+/// Adds business days to a date, skipping Saturdays and Sundays
+/// 
+/// # Arguments
+/// * `date_str` - The input date string in YYYY-MM-DD format
+/// * `days` - The number of business days to add (can be negative to subtract)
+/// 
+/// # Returns
+/// The new date string or an error
+/// 
+/// # Panics
+/// Panics if the resulting date cannot be represented (roughly beyond
+/// ±262,000 years), like `add_days`.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::add_business_days;
+/// // 2023-12-29 is a Friday
+/// assert_eq!(add_business_days("2023-12-29", 1).unwrap(), "2024-01-01");
+/// assert_eq!(add_business_days("2024-01-01", -1).unwrap(), "2023-12-29");
+/// ```
+pub fn add_business_days(date_str: &str, days: i64) -> Result<String, chrono::ParseError> {
+    let mut date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    if days == 0 {
+        return Ok(date.format("%Y-%m-%d").to_string());
+    }
+    // Every 5 business days after the first few span exactly one calendar
+    // week, so jump over whole weeks and only step through the last 1 to 5
+    let whole_weeks = (days.unsigned_abs() - 1) / 5;
+    let mut remaining = days.unsigned_abs() - whole_weeks * 5;
+    let step = Duration::days(days.signum());
+    date = (whole_weeks as i64)
+        .checked_mul(7 * days.signum())
+        .and_then(Duration::try_days)
+        .and_then(|duration| date.checked_add_signed(duration))
+        .expect("date out of range");
+    while remaining > 0 {
+        date = date.checked_add_signed(step).expect("date out of range");
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            remaining -= 1;
+        }
+    }
+    Ok(date.format("%Y-%m-%d").to_string())
+}
+
+/// Calculates the date of Easter Sunday in the Gregorian calendar
+/// 
+/// Uses the Anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+/// 
+/// # Arguments
+/// * `year` - The year to calculate Easter for
+/// 
+/// # Returns
+/// The date of Easter Sunday in YYYY-MM-DD format
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::easter;
+/// assert_eq!(easter(2024), "2024-03-31");
+/// ```
+pub fn easter(year: i32) -> String {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Gets the number of days in a month
+/// 
+/// # Arguments
+/// * `year` - The year (used to account for leap years)
+/// * `month` - The month, from 1 (January) to 12 (December)
+/// 
+/// # Returns
+/// The number of days in the month, or `None` if the month is out of range
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::days_in_month;
+/// assert_eq!(days_in_month(2024, 2), Some(29));
+/// assert_eq!(days_in_month(2023, 4), Some(30));
+/// assert_eq!(days_in_month(2023, 13), None);
+/// ```
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// Checks if a year, month and day combination is a real calendar date
+/// 
+/// # Arguments
+/// * `year` - The year
+/// * `month` - The month, from 1 to 12
+/// * `day` - The day of the month
+/// 
+/// # Returns
+/// `true` if the day exists in the given month and year, `false` otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::is_real_date;
+/// assert!(is_real_date(2024, 2, 29));
+/// assert!(!is_real_date(2023, 2, 30));
+/// ```
+pub fn is_real_date(year: i32, month: u32, day: u32) -> bool {
+    match days_in_month(year, month) {
+        Some(days) => day >= 1 && day <= days,
+        None => false,
+    }
+}
+
+/// Gets the current date and time in UTC in the specified format
+/// 
+/// # Arguments
+/// * `format` - The desired format string
+/// 
+/// # Returns
+/// The current UTC date and time formatted as requested
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::current_datetime_utc;
+/// let now = current_datetime_utc("%Y-%m-%d %H:%M:%S %z");
+/// assert!(now.ends_with("+0000"));
+/// ```
+pub fn current_datetime_utc(format: &str) -> String {
+    Utc::now().format(format).to_string()
+}
+
+/// Gets the current date and time in a named IANA timezone in the specified format
+/// 
+/// # Arguments
+/// * `format` - The desired format string
+/// * `tz` - The IANA timezone name (e.g., "America/New_York")
+/// 
+/// # Returns
+/// The current date and time in the timezone, or an error if the timezone is unknown
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::current_datetime_in_tz;
+/// assert_eq!(current_datetime_in_tz("%z", "Asia/Tokyo").unwrap(), "+0900");
+/// assert!(current_datetime_in_tz("%z", "Mars/Olympus_Mons").is_err());
+/// ```
+pub fn current_datetime_in_tz(format: &str, tz: &str) -> Result<String, String> {
+    let zone: Tz = tz.parse().map_err(|_| format!("Unknown timezone: {}", tz))?;
+    Ok(Utc::now().with_timezone(&zone).format(format).to_string())
+}
+
+/// Gets the Western zodiac sign for a given date
+/// 
+/// # Arguments
+/// * `date_str` - The date string in YYYY-MM-DD format
+/// 
+/// # Returns
+/// The name of the zodiac sign or an error
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::zodiac_sign;
+/// assert_eq!(zodiac_sign("2023-03-21").unwrap(), "Aries");
+/// assert_eq!(zodiac_sign("2023-03-20").unwrap(), "Pisces");
+/// ```
+pub fn zodiac_sign(date_str: &str) -> Result<String, chrono::ParseError> {
+    // (month, first day, sign) for each sign's start date, in calendar order
+    const SIGNS: [(u32, u32, &str); 12] = [
+        (1, 20, "Aquarius"),
+        (2, 19, "Pisces"),
+        (3, 21, "Aries"),
+        (4, 20, "Taurus"),
+        (5, 21, "Gemini"),
+        (6, 21, "Cancer"),
+        (7, 23, "Leo"),
+        (8, 23, "Virgo"),
+        (9, 23, "Libra"),
+        (10, 23, "Scorpio"),
+        (11, 22, "Sagittarius"),
+        (12, 22, "Capricorn"),
+    ];
+
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    let sign = SIGNS
+        .iter()
+        .rev()
+        .find(|(month, day, _)| (date.month(), date.day()) >= (*month, *day))
+        .map_or("Capricorn", |(_, _, sign)| sign);
+    Ok(sign.to_string())
+}
+
+/// Parses a duration string like "1h30m" into a total number of seconds
+/// 
+/// The string is a sequence of numbers each followed by a unit: `d` (days),
+/// `h` (hours), `m` (minutes) or `s` (seconds). Units may appear in any order.
+/// 
+/// # Arguments
+/// * `s` - The duration string to parse
+/// 
+/// # Returns
+/// The total number of seconds, or `None` if the string is malformed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::parse_duration;
+/// assert_eq!(parse_duration("1h30m"), Some(5400));
+/// assert_eq!(parse_duration("2d"), Some(172800));
+/// assert_eq!(parse_duration("90"), None);
+/// ```
+pub fn parse_duration(s: &str) -> Option<i64> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    let mut total: i64 = 0;
+    let mut number = String::new();
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let multiplier = match c {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        if number.is_empty() {
+            return None;
+        }
+        let value: i64 = number.parse().ok()?;
+        total = total.checked_add(value.checked_mul(multiplier)?)?;
+        number.clear();
+    }
+    if number.is_empty() {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// Formats a number of seconds as a human-readable duration like "1h 30m 15s"
+/// 
+/// This is the inverse of `parse_duration`. Zero components are omitted,
+/// zero seconds is formatted as "0s", and negative durations get a leading "-".
+/// 
+/// # Arguments
+/// * `secs` - The duration in seconds
+/// 
+/// # Returns
+/// The formatted duration string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::format_duration;
+/// assert_eq!(format_duration(5415), "1h 30m 15s");
+/// assert_eq!(format_duration(-90), "-1m 30s");
+/// ```
+pub fn format_duration(secs: i64) -> String {
+    if secs == 0 {
+        return "0s".to_string();
+    }
+    let mut remaining = secs.unsigned_abs();
+    let mut parts = Vec::new();
+    for (unit, size) in [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)] {
+        let count = remaining / size;
+        remaining %= size;
+        if count > 0 {
+            parts.push(format!("{}{}", count, unit));
+        }
+    }
+    let sign = if secs < 0 { "-" } else { "" };
+    format!("{}{}", sign, parts.join(" "))
+}
+
+/// Calculates the number of whole weeks between two dates
+/// 
+/// # Arguments
+/// * `a` - The first date in YYYY-MM-DD format
+/// * `b` - The second date in YYYY-MM-DD format
+/// 
+/// # Returns
+/// The number of complete weeks between the dates (positive if a > b),
+/// ignoring any remaining days
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::weeks_between;
+/// assert_eq!(weeks_between("2023-01-20", "2023-01-05").unwrap(), 2);
+/// assert_eq!(weeks_between("2023-01-05", "2023-01-20").unwrap(), -2);
+/// ```
+pub fn weeks_between(a: &str, b: &str) -> Result<i64, chrono::ParseError> {
+    Ok(date_difference_days(a, b)? / 7)
+}
+
+/// Calculates the number of whole calendar months between two dates
+/// 
+/// A month counts only once the day of the month has been reached again, so
+/// 2023-01-15 to 2023-02-14 is 0 months and to 2023-02-15 is 1 month. Moving
+/// from a day that doesn't exist in a shorter month (e.g. the 31st) likewise
+/// only counts once that day number is reached.
+/// 
+/// # Arguments
+/// * `a` - The first date in YYYY-MM-DD format
+/// * `b` - The second date in YYYY-MM-DD format
+/// 
+/// # Returns
+/// The number of complete months between the dates (positive if a > b)
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::months_between;
+/// assert_eq!(months_between("2023-03-15", "2023-01-15").unwrap(), 2);
+/// assert_eq!(months_between("2023-03-14", "2023-01-15").unwrap(), 1);
+/// ```
+pub fn months_between(a: &str, b: &str) -> Result<i64, chrono::ParseError> {
+    let a = NaiveDate::parse_from_str(a, "%Y-%m-%d")?;
+    let b = NaiveDate::parse_from_str(b, "%Y-%m-%d")?;
+    let mut months = (a.year() as i64 * 12 + a.month() as i64) - (b.year() as i64 * 12 + b.month() as i64);
+    if months > 0 && a.day() < b.day() {
+        months -= 1;
+    } else if months < 0 && a.day() > b.day() {
+        months += 1;
+    }
+    Ok(months)
+}
+
+/// Formats a date in words with an ordinal day, like "25th of December, 2023"
+/// 
+/// # Arguments
+/// * `date_str` - The input date string in YYYY-MM-DD format
+/// 
+/// # Returns
+/// The formatted date string or an error
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::format_ordinal;
+/// assert_eq!(format_ordinal("2023-12-25").unwrap(), "25th of December, 2023");
+/// ```
+pub fn format_ordinal(date_str: &str) -> Result<String, chrono::ParseError> {
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    Ok(format!("{} of {}", ordinal(date.day() as u64), date.format("%B, %Y")))
+}
+
+/// Checks whether a date falls on a weekday (Monday to Friday)
+/// 
+/// # Arguments
+/// * `date_str` - The date string in YYYY-MM-DD format
+/// 
+/// # Returns
+/// true if the date is Monday to Friday, false on weekends, or an error
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::is_weekday;
+/// assert!(is_weekday("2023-12-29").unwrap()); // Friday
+/// assert!(!is_weekday("2023-12-30").unwrap()); // Saturday
+/// ```
+pub fn is_weekday(date_str: &str) -> Result<bool, chrono::ParseError> {
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    Ok(!matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+}
+
+/// Checks whether a date is a business day: a weekday that is not a holiday
+/// 
+/// # Arguments
+/// * `date_str` - The date string in YYYY-MM-DD format
+/// * `holidays` - Holiday dates in YYYY-MM-DD format
+/// 
+/// # Returns
+/// true if the date is a weekday not in `holidays`, false otherwise, or an
+/// error if the date or any holiday fails to parse
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::is_business_day;
+/// let holidays = ["2023-12-25", "2024-01-01"];
+/// assert!(!is_business_day("2023-12-25", &holidays).unwrap());
+/// assert!(is_business_day("2023-12-27", &holidays).unwrap());
+/// ```
+pub fn is_business_day(date_str: &str, holidays: &[&str]) -> Result<bool, chrono::ParseError> {
+    if !is_weekday(date_str)? {
+        return Ok(false);
+    }
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    for holiday in holidays {
+        if NaiveDate::parse_from_str(holiday, "%Y-%m-%d")? == date {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// The Julian Day Number of 0000-12-31 (proleptic Gregorian), the day before
+/// chrono's day 1 of the common era
+const JULIAN_DAY_OFFSET: i64 = 1_721_425;
+
+/// Converts a date to its Julian Day Number
+/// 
+/// The Julian Day Number counts days continuously from noon on
+/// 4713-11-24 BC (proleptic Gregorian) and is widely used in astronomy.
+/// 
+/// # Arguments
+/// * `date_str` - The date string in YYYY-MM-DD format
+/// 
+/// # Returns
+/// The Julian Day Number or an error
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::to_julian_day;
+/// assert_eq!(to_julian_day("2000-01-01").unwrap(), 2451545);
+/// ```
+pub fn to_julian_day(date_str: &str) -> Result<i64, chrono::ParseError> {
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")?;
+    Ok(date.num_days_from_ce() as i64 + JULIAN_DAY_OFFSET)
+}
+
+/// Converts a Julian Day Number back to a date
+/// 
+/// # Arguments
+/// * `jdn` - The Julian Day Number
+/// 
+/// # Returns
+/// The date in YYYY-MM-DD format
+/// 
+/// # Panics
+/// Panics if the day number is outside the range of dates chrono can
+/// represent (roughly ±262,000 years).
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::from_julian_day;
+/// assert_eq!(from_julian_day(2451545), "2000-01-01");
+/// ```
+pub fn from_julian_day(jdn: i64) -> String {
+    let date = i32::try_from(jdn - JULIAN_DAY_OFFSET)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .expect("Julian Day Number out of range");
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Finds the first leap year strictly after a given year
+/// 
+/// # Arguments
+/// * `year` - The year to start from
+/// 
+/// # Returns
+/// The next leap year, skipping century years not divisible by 400
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::next_leap_year;
+/// assert_eq!(next_leap_year(2023), 2024);
+/// assert_eq!(next_leap_year(1896), 1904);
+/// ```
+pub fn next_leap_year(year: i32) -> i32 {
+    let mut candidate = year + 1;
+    while !is_leap_year(candidate) {
+        candidate += 1;
+    }
+    candidate
+}
+
+/// Finds the last leap year strictly before a given year
+/// 
+/// # Arguments
+/// * `year` - The year to start from
+/// 
+/// # Returns
+/// The previous leap year, skipping century years not divisible by 400
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::previous_leap_year;
+/// assert_eq!(previous_leap_year(2024), 2020);
+/// assert_eq!(previous_leap_year(1904), 1896);
+/// ```
+pub fn previous_leap_year(year: i32) -> i32 {
+    let mut candidate = year - 1;
+    while !is_leap_year(candidate) {
+        candidate -= 1;
+    }
+    candidate
+}
+
+/// Checks whether a strftime-style format string is valid for chrono
+/// 
+/// The format is rejected if it contains an unknown specifier (such as `%Q`)
+/// or a dangling `%`. Valid formats are also test-rendered against a fixed
+/// UTC date-time, so any specifier usable with `current_date` is accepted,
+/// including time and time zone specifiers.
+/// 
+/// # Arguments
+/// * `format` - The format string to check
+/// 
+/// # Returns
+/// true if the format can be used to format dates, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::date_utils::is_valid_format;
+/// assert!(is_valid_format("%Y-%m-%d %H:%M"));
+/// assert!(!is_valid_format("%Y-%Q"));
+/// ```
+pub fn is_valid_format(format: &str) -> bool {
+    use chrono::format::{Item, StrftimeItems};
+    use std::fmt::Write;
+
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return false;
+    }
+    let reference = NaiveDate::from_ymd_opt(2000, 1, 2)
+        .and_then(|date| date.and_hms_opt(3, 4, 5))
+        .expect("reference date is valid")
+        .and_utc();
+    let mut rendered = String::new();
+    write!(rendered, "{}", reference.format(format)).is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,10 +902,10 @@ mod tests {
 
     #[test]
     fn test_validate_date_format() {
-        assert_eq!(validate_date_format("2023-12-25", "%Y-%m-%d"), true);
-        assert_eq!(validate_date_format("25/12/2023", "%d/%m/%Y"), true);
-        assert_eq!(validate_date_format("2023-13-25", "%Y-%m-%d"), false);
-        assert_eq!(validate_date_format("invalid", "%Y-%m-%d"), false);
+        assert!(validate_date_format("2023-12-25", "%Y-%m-%d"));
+        assert!(validate_date_format("25/12/2023", "%d/%m/%Y"));
+        assert!(!validate_date_format("2023-13-25", "%Y-%m-%d"));
+        assert!(!validate_date_format("invalid", "%Y-%m-%d"));
     }
 
     #[test]
@@ -232,11 +940,11 @@ mod tests {
 
     #[test]
     fn test_is_leap_year() {
-        assert_eq!(is_leap_year(2024), true);
-        assert_eq!(is_leap_year(2023), false);
-        assert_eq!(is_leap_year(2000), true);
-        assert_eq!(is_leap_year(1900), false);
-        assert_eq!(is_leap_year(2004), true);
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2004));
     }
 
     #[test]
@@ -244,4 +952,389 @@ mod tests {
         assert_eq!(day_of_week("2023-12-25").unwrap(), "Monday");
         assert_eq!(day_of_week("2024-01-01").unwrap(), "Monday");
     }
+
+    #[test]
+    fn test_validate_datetime_format() {
+        assert!(validate_datetime_format("2023-12-25 14:30:00", "%Y-%m-%d %H:%M:%S"));
+        assert!(validate_datetime_format("25/12/2023 00:00", "%d/%m/%Y %H:%M"));
+        assert!(validate_datetime_format("2023-12-25 23:59:59", "%Y-%m-%d %H:%M:%S"));
+    }
+
+    #[test]
+    fn test_validate_datetime_format_invalid_time() {
+        assert!(!validate_datetime_format("2023-12-25 25:00:00", "%Y-%m-%d %H:%M:%S"));
+        assert!(!validate_datetime_format("2023-12-25 14:60:00", "%Y-%m-%d %H:%M:%S"));
+        assert!(!validate_datetime_format("2023-12-25", "%Y-%m-%d %H:%M:%S"));
+        assert!(!validate_datetime_format("2023-02-30 12:00:00", "%Y-%m-%d %H:%M:%S"));
+    }
+
+    #[test]
+    fn test_datetime_difference_sub_day() {
+        assert_eq!(datetime_difference_seconds("2023-01-01 14:30:15", "2023-01-01 12:00:00").unwrap(), 9015);
+        assert_eq!(datetime_difference_minutes("2023-01-01 14:30:15", "2023-01-01 12:00:00").unwrap(), 150);
+        assert_eq!(datetime_difference_hours("2023-01-01 14:30:15", "2023-01-01 12:00:00").unwrap(), 2);
+        assert_eq!(datetime_difference_seconds("2023-01-01 12:00:00", "2023-01-01 12:00:10").unwrap(), -10);
+    }
+
+    #[test]
+    fn test_datetime_difference_multi_day() {
+        assert_eq!(datetime_difference_seconds("2023-01-03 06:00:00", "2023-01-01 00:00:00").unwrap(), 194400);
+        assert_eq!(datetime_difference_minutes("2023-01-03 06:00:00", "2023-01-01 00:00:00").unwrap(), 3240);
+        assert_eq!(datetime_difference_hours("2023-01-03 06:00:00", "2023-01-01 00:00:00").unwrap(), 54);
+        assert_eq!(datetime_difference_hours("2023-01-01 00:00:00", "2023-01-03 06:00:00").unwrap(), -54);
+        assert!(datetime_difference_seconds("2023-01-01", "2023-01-01 00:00:00").is_err());
+    }
+
+    #[test]
+    fn test_add_weeks() {
+        assert_eq!(add_weeks("2023-12-25", 1).unwrap(), "2024-01-01");
+        assert_eq!(add_weeks("2023-12-25", -2).unwrap(), "2023-12-11");
+        assert_eq!(add_weeks("2023-12-25", 0).unwrap(), "2023-12-25");
+        assert!(add_weeks("invalid", 1).is_err());
+    }
+
+    #[test]
+    fn test_add_business_days_skips_weekend() {
+        // 2023-12-29 is a Friday
+        assert_eq!(add_business_days("2023-12-29", 1).unwrap(), "2024-01-01");
+        assert_eq!(add_business_days("2023-12-29", 6).unwrap(), "2024-01-08");
+        // Starting on a Saturday
+        assert_eq!(add_business_days("2023-12-30", 1).unwrap(), "2024-01-01");
+        assert_eq!(add_business_days("2023-12-27", 2).unwrap(), "2023-12-29");
+        assert_eq!(add_business_days("2023-12-30", 0).unwrap(), "2023-12-30");
+    }
+
+    #[test]
+    fn test_add_business_days_negative() {
+        // 2024-01-01 is a Monday
+        assert_eq!(add_business_days("2024-01-01", -1).unwrap(), "2023-12-29");
+        assert_eq!(add_business_days("2024-01-01", -5).unwrap(), "2023-12-25");
+        assert_eq!(add_business_days("2023-12-31", -1).unwrap(), "2023-12-29");
+    }
+
+    #[test]
+    fn test_add_business_days_long_spans() {
+        // Starting on a weekend
+        assert_eq!(add_business_days("2023-12-30", 10).unwrap(), "2024-01-12");
+        assert_eq!(add_business_days("2023-12-31", -10).unwrap(), "2023-12-18");
+        // 52 weeks of business days from a Monday
+        assert_eq!(add_business_days("2024-01-01", 260).unwrap(), "2024-12-30");
+        assert_eq!(add_business_days("2024-12-30", -260).unwrap(), "2024-01-01");
+    }
+
+    #[test]
+    fn test_add_business_days_invalid_date() {
+        assert!(add_business_days("invalid", 1).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "date out of range")]
+    fn test_add_weeks_out_of_range() {
+        let _ = add_weeks("2023-12-25", i64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "date out of range")]
+    fn test_add_business_days_out_of_range() {
+        let _ = add_business_days("2024-01-01", i64::MAX);
+    }
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(easter(2024), "2024-03-31");
+        assert_eq!(easter(2023), "2023-04-09");
+        assert_eq!(easter(2025), "2025-04-20");
+        assert_eq!(easter(2000), "2000-04-23");
+        assert_eq!(easter(1961), "1961-04-02");
+        assert_eq!(easter(2038), "2038-04-25");
+        assert_eq!(day_of_week(&easter(2026)).unwrap(), "Sunday");
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2023, 1), Some(31));
+        assert_eq!(days_in_month(2023, 2), Some(28));
+        assert_eq!(days_in_month(2024, 2), Some(29));
+        assert_eq!(days_in_month(1900, 2), Some(28));
+        assert_eq!(days_in_month(2023, 11), Some(30));
+        assert_eq!(days_in_month(2023, 0), None);
+        assert_eq!(days_in_month(2023, 13), None);
+    }
+
+    #[test]
+    fn test_is_real_date_leap_day() {
+        assert!(is_real_date(2024, 2, 29));
+        assert!(is_real_date(2000, 2, 29));
+        assert!(!is_real_date(2023, 2, 29));
+        assert!(!is_real_date(1900, 2, 29));
+        assert!(!is_real_date(2023, 2, 30));
+    }
+
+    #[test]
+    fn test_is_real_date_month_lengths() {
+        assert!(is_real_date(2023, 1, 31));
+        assert!(!is_real_date(2023, 4, 31));
+        assert!(is_real_date(2023, 4, 30));
+        assert!(is_real_date(2023, 12, 31));
+        assert!(!is_real_date(2023, 12, 32));
+        assert!(!is_real_date(2023, 6, 0));
+        assert!(!is_real_date(2023, 13, 1));
+    }
+
+    #[test]
+    fn test_current_datetime_utc() {
+        let now = current_datetime_utc("%Y-%m-%d %H:%M:%S");
+        assert!(validate_datetime_format(&now, "%Y-%m-%d %H:%M:%S"));
+        assert_eq!(current_datetime_utc("%z"), "+0000");
+    }
+
+    #[test]
+    fn test_current_datetime_utc_and_local_differ_when_offset_nonzero() {
+        let local_offset = Local::now().offset().local_minus_utc();
+        let local = current_date("%z");
+        let utc = current_datetime_utc("%z");
+        assert_eq!(local != utc, local_offset != 0);
+    }
+
+    #[test]
+    fn test_current_datetime_in_tz() {
+        // Asia/Tokyo and Asia/Kolkata do not observe daylight saving time
+        assert_eq!(current_datetime_in_tz("%z", "Asia/Tokyo").unwrap(), "+0900");
+        assert_eq!(current_datetime_in_tz("%z", "Asia/Kolkata").unwrap(), "+0530");
+        assert_eq!(current_datetime_in_tz("%z", "UTC").unwrap(), "+0000");
+        let now = current_datetime_in_tz("%Y-%m-%d %H:%M:%S", "America/New_York").unwrap();
+        assert!(validate_datetime_format(&now, "%Y-%m-%d %H:%M:%S"));
+    }
+
+    #[test]
+    fn test_current_datetime_in_tz_invalid_zone() {
+        let err = current_datetime_in_tz("%Y-%m-%d", "Not/A_Zone").unwrap_err();
+        assert_eq!(err, "Unknown timezone: Not/A_Zone");
+    }
+
+    #[test]
+    fn test_zodiac_sign_boundaries() {
+        let boundaries = [
+            ("2023-01-19", "Capricorn"), ("2023-01-20", "Aquarius"),
+            ("2023-02-18", "Aquarius"), ("2023-02-19", "Pisces"),
+            ("2023-03-20", "Pisces"), ("2023-03-21", "Aries"),
+            ("2023-04-19", "Aries"), ("2023-04-20", "Taurus"),
+            ("2023-05-20", "Taurus"), ("2023-05-21", "Gemini"),
+            ("2023-06-20", "Gemini"), ("2023-06-21", "Cancer"),
+            ("2023-07-22", "Cancer"), ("2023-07-23", "Leo"),
+            ("2023-08-22", "Leo"), ("2023-08-23", "Virgo"),
+            ("2023-09-22", "Virgo"), ("2023-09-23", "Libra"),
+            ("2023-10-22", "Libra"), ("2023-10-23", "Scorpio"),
+            ("2023-11-21", "Scorpio"), ("2023-11-22", "Sagittarius"),
+            ("2023-12-21", "Sagittarius"), ("2023-12-22", "Capricorn"),
+        ];
+        for (date, sign) in boundaries {
+            assert_eq!(zodiac_sign(date).unwrap(), sign, "wrong sign for {}", date);
+        }
+    }
+
+    #[test]
+    fn test_zodiac_sign_year_wrap() {
+        assert_eq!(zodiac_sign("2023-01-01").unwrap(), "Capricorn");
+        assert_eq!(zodiac_sign("2023-12-31").unwrap(), "Capricorn");
+        assert!(zodiac_sign("not-a-date").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration_single_unit() {
+        assert_eq!(parse_duration("45s"), Some(45));
+        assert_eq!(parse_duration("90m"), Some(5400));
+        assert_eq!(parse_duration("2h"), Some(7200));
+        assert_eq!(parse_duration("2d"), Some(172800));
+        assert_eq!(parse_duration("0s"), Some(0));
+    }
+
+    #[test]
+    fn test_parse_duration_multi_unit() {
+        assert_eq!(parse_duration("1h30m"), Some(5400));
+        assert_eq!(parse_duration("1d2h3m4s"), Some(93784));
+        assert_eq!(parse_duration("30m1h"), Some(5400));
+        assert_eq!(parse_duration(" 1m30s "), Some(90));
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("abc"), None);
+        assert_eq!(parse_duration("90"), None);
+        assert_eq!(parse_duration("1h30"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration("5w"), None);
+        assert_eq!(parse_duration("1h 30m"), None);
+        assert_eq!(parse_duration("-5s"), None);
+        assert_eq!(parse_duration("99999999999999999999d"), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(5415), "1h 30m 15s");
+        assert_eq!(format_duration(93784), "1d 2h 3m 4s");
+        assert_eq!(format_duration(3600), "1h");
+        assert_eq!(format_duration(86460), "1d 1m");
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(-3600), "-1h");
+        assert_eq!(format_duration(-90), "-1m 30s");
+    }
+
+    #[test]
+    fn test_format_duration_round_trip() {
+        for secs in [1, 59, 60, 3599, 5415, 93784, 1_000_000] {
+            let formatted = format_duration(secs).replace(' ', "");
+            assert_eq!(parse_duration(&formatted), Some(secs));
+        }
+    }
+
+    #[test]
+    fn test_checked_add_days() {
+        assert_eq!(checked_add_days("2023-12-25", 7).unwrap(), "2024-01-01");
+        assert_eq!(checked_add_days("2024-03-01", -1).unwrap(), "2024-02-29");
+        assert_eq!(checked_add_days("2023-01-01", 0).unwrap(), add_days("2023-01-01", 0).unwrap());
+
+        assert_eq!(checked_add_days("2023-01-01", 1_000_000_000), Err(DateError::OutOfRange));
+        assert_eq!(checked_add_days("2023-01-01", -1_000_000_000), Err(DateError::OutOfRange));
+        assert_eq!(checked_add_days("2023-01-01", i64::MAX), Err(DateError::OutOfRange));
+        assert_eq!(checked_add_days("2023-01-01", i64::MIN), Err(DateError::OutOfRange));
+        assert_eq!(
+            DateError::OutOfRange.to_string(),
+            "date is out of the representable range"
+        );
+
+        let err = checked_add_days("not-a-date", 1).unwrap_err();
+        assert!(matches!(err, DateError::Parse(_)));
+        assert!(err.to_string().starts_with("invalid date: "));
+    }
+
+    #[test]
+    fn test_weeks_between() {
+        assert_eq!(weeks_between("2023-01-15", "2023-01-01").unwrap(), 2);
+        assert_eq!(weeks_between("2023-01-14", "2023-01-01").unwrap(), 1);
+        assert_eq!(weeks_between("2023-01-07", "2023-01-01").unwrap(), 0);
+        assert_eq!(weeks_between("2023-01-01", "2023-01-15").unwrap(), -2);
+        assert_eq!(weeks_between("2023-01-01", "2023-01-14").unwrap(), -1);
+        assert!(weeks_between("2023-13-01", "2023-01-01").is_err());
+    }
+
+    #[test]
+    fn test_months_between() {
+        assert_eq!(months_between("2023-03-15", "2023-01-15").unwrap(), 2);
+        assert_eq!(months_between("2024-01-15", "2023-01-15").unwrap(), 12);
+        // Partial months don't count
+        assert_eq!(months_between("2023-03-14", "2023-01-15").unwrap(), 1);
+        assert_eq!(months_between("2023-01-31", "2023-01-01").unwrap(), 0);
+        assert_eq!(months_between("2023-02-28", "2023-01-31").unwrap(), 0);
+        // Negative direction
+        assert_eq!(months_between("2023-01-15", "2023-03-15").unwrap(), -2);
+        assert_eq!(months_between("2023-01-15", "2023-03-14").unwrap(), -1);
+        assert_eq!(months_between("2022-12-20", "2023-01-10").unwrap(), 0);
+        assert!(months_between("2023-01-15", "bogus").is_err());
+    }
+
+    #[test]
+    fn test_format_ordinal() {
+        assert_eq!(format_ordinal("2023-01-01").unwrap(), "1st of January, 2023");
+        assert_eq!(format_ordinal("2023-02-02").unwrap(), "2nd of February, 2023");
+        assert_eq!(format_ordinal("2023-03-03").unwrap(), "3rd of March, 2023");
+        assert_eq!(format_ordinal("2023-11-11").unwrap(), "11th of November, 2023");
+        assert_eq!(format_ordinal("2023-05-22").unwrap(), "22nd of May, 2023");
+        assert_eq!(format_ordinal("2024-02-29").unwrap(), "29th of February, 2024");
+        assert!(format_ordinal("2023-02-29").is_err());
+    }
+
+    #[test]
+    fn test_is_weekday() {
+        assert!(is_weekday("2024-01-01").unwrap()); // Monday
+        assert!(is_weekday("2024-01-05").unwrap()); // Friday
+        assert!(!is_weekday("2024-01-06").unwrap()); // Saturday
+        assert!(!is_weekday("2024-01-07").unwrap()); // Sunday
+        assert!(is_weekday("2024-13-01").is_err());
+    }
+
+    #[test]
+    fn test_is_business_day() {
+        let holidays = ["2023-12-25", "2023-12-26", "2024-01-01"];
+        // Weekday holidays
+        assert!(!is_business_day("2023-12-25", &holidays).unwrap());
+        assert!(!is_business_day("2024-01-01", &holidays).unwrap());
+        // Non-holiday weekdays
+        assert!(is_business_day("2023-12-27", &holidays).unwrap());
+        assert!(is_business_day("2023-12-25", &[]).unwrap());
+        // Weekends
+        assert!(!is_business_day("2023-12-30", &holidays).unwrap());
+        assert!(!is_business_day("2023-12-31", &[]).unwrap());
+        // Malformed input
+        assert!(is_business_day("2023-12-32", &holidays).is_err());
+        assert!(is_business_day("2023-12-27", &["not-a-date"]).is_err());
+    }
+
+    #[test]
+    fn test_julian_day() {
+        assert_eq!(to_julian_day("2000-01-01").unwrap(), 2_451_545);
+        assert_eq!(to_julian_day("1970-01-01").unwrap(), 2_440_588);
+        assert_eq!(to_julian_day("1858-11-17").unwrap(), 2_400_001);
+        assert_eq!(from_julian_day(2_451_545), "2000-01-01");
+        assert_eq!(from_julian_day(2_440_588), "1970-01-01");
+        assert!(to_julian_day("2000-02-30").is_err());
+    }
+
+    #[test]
+    fn test_julian_day_round_trip() {
+        for date in ["2000-01-01", "1900-02-28", "2024-02-29", "1582-10-15", "0001-01-01", "2999-12-31"] {
+            assert_eq!(from_julian_day(to_julian_day(date).unwrap()), date);
+        }
+        for jdn in 2_451_000..2_452_000 {
+            assert_eq!(to_julian_day(&from_julian_day(jdn)).unwrap(), jdn);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Julian Day Number out of range")]
+    fn test_from_julian_day_out_of_range() {
+        from_julian_day(i64::MAX);
+    }
+
+    #[test]
+    fn test_next_leap_year() {
+        assert_eq!(next_leap_year(2023), 2024);
+        assert_eq!(next_leap_year(2024), 2028);
+        // 1900 is not a leap year, 2000 is
+        assert_eq!(next_leap_year(1896), 1904);
+        assert_eq!(next_leap_year(1899), 1904);
+        assert_eq!(next_leap_year(1996), 2000);
+        assert_eq!(next_leap_year(1999), 2000);
+        assert_eq!(next_leap_year(2000), 2004);
+    }
+
+    #[test]
+    fn test_previous_leap_year() {
+        assert_eq!(previous_leap_year(2024), 2020);
+        assert_eq!(previous_leap_year(2025), 2024);
+        assert_eq!(previous_leap_year(1904), 1896);
+        assert_eq!(previous_leap_year(1901), 1896);
+        assert_eq!(previous_leap_year(2004), 2000);
+        assert_eq!(previous_leap_year(2001), 2000);
+        assert_eq!(previous_leap_year(2000), 1996);
+    }
+
+    #[test]
+    fn test_is_valid_format() {
+        assert!(is_valid_format("%Y-%m-%d"));
+        assert!(is_valid_format("%d/%m/%Y"));
+        assert!(is_valid_format("%A, %B %e %Y at %H:%M:%S %z"));
+        assert!(is_valid_format("%%literal percent"));
+        assert!(is_valid_format("no specifiers"));
+        assert!(is_valid_format(""));
+    }
+
+    #[test]
+    fn test_is_valid_format_invalid() {
+        assert!(!is_valid_format("%Q"));
+        assert!(!is_valid_format("%Y-%m-%Q"));
+        assert!(!is_valid_format("100%"));
+        assert!(!is_valid_format("%"));
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 //! This is a library that provides utilities for command-line tools.
-//! So far it only provides a function to read a line from stdin.
+//! At the crate root it provides functions to read a line, or every line, from stdin.
 //! # Examples:
 //! ```
 //! use cli_utils::read_stdin;
@@ -8,7 +8,7 @@
 //! # Panics:
 //! The `read_stdin` function will panic if it fails to read a line with a message "Failed to read input line".
 
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader};
 
 pub mod config;
 pub mod colors;
@@ -16,6 +16,11 @@ pub mod math_utils;
 pub mod string_utils;
 pub mod date_utils;
 pub mod file_io_utils;
+pub mod stopwatch;
+pub mod progress;
+
+pub use stopwatch::Stopwatch;
+pub use progress::ProgressBar;
 
 
 /// This function reads a line from stdin and returns it as a String.
@@ -31,6 +36,19 @@ pub fn read_stdin() -> String {
     _read_stdin(&mut reader)
 }
 
+/// This function returns an iterator over the lines of stdin, without their line endings.
+/// It is meant for Unix-style pipelines, where a command processes each line of its input.
+/// # Examples:
+/// ```no_run
+/// use cli_utils::read_stdin_lines;
+/// for line in read_stdin_lines() {
+///     println!("{}", line.expect("Failed to read input line"));
+/// }
+/// ```
+pub fn read_stdin_lines() -> impl Iterator<Item = io::Result<String>> {
+    io::stdin().lines()
+}
+
 fn _read_stdin<R: BufRead>(reader: &mut R) -> String {
     let mut line = String::new();
     reader
@@ -1,6 +1,224 @@
-use cli_utils::{math_utils, string_utils, date_utils, file_io_utils, read_stdin};
+use clap::{Parser, Subcommand};
+use cli_utils::{math_utils, string_utils, date_utils, file_io_utils, read_stdin, read_stdin_lines};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Math, string, date, and file utilities for the command line.
+///
+/// Run without a subcommand to start the interactive menu.
+#[derive(Parser)]
+#[command(name = "cli-utils", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Date utilities
+    Date {
+        #[command(subcommand)]
+        command: DateCommands,
+    },
+    /// File utilities
+    File {
+        #[command(subcommand)]
+        command: FileCommands,
+    },
+    /// Math utilities
+    Math {
+        #[command(subcommand)]
+        command: MathCommands,
+    },
+    /// String utilities
+    String {
+        #[command(subcommand)]
+        command: StringCommands,
+    },
+    /// Re-run a subcommand whenever a file changes, until interrupted
+    ///
+    /// Example: cli-utils watch notes.txt -- file stats notes.txt
+    Watch {
+        /// The file to watch for modifications
+        path: PathBuf,
+        /// The cli-utils subcommand to run, given after "--"
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+}
+
+/// How often `watch` checks the file's modification time
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The largest limit `math primes` accepts, which keeps the sieve to about 10 MB
+const MAX_PRIMES_LIMIT: u64 = 10_000_000;
+
+#[derive(Subcommand)]
+enum DateCommands {
+    /// Print the number of days from the second date to the first
+    Diff {
+        #[arg(value_parser = parse_date_arg)]
+        date1: String,
+        #[arg(value_parser = parse_date_arg)]
+        date2: String,
+    },
+    /// Print the date a number of days (possibly negative) after a date
+    Add {
+        #[arg(value_parser = parse_date_arg)]
+        date: String,
+        #[arg(allow_hyphen_values = true)]
+        days: i64,
+    },
+    /// Print the day of the week of a date
+    Weekday {
+        #[arg(value_parser = parse_date_arg)]
+        date: String,
+    },
+    /// Print whether a year is a leap year
+    Leap { year: i32 },
+}
+
+#[derive(Subcommand)]
+enum FileCommands {
+    /// Print line, word, and character counts and the most frequent words of a text file
+    Stats {
+        /// The text file to analyze
+        path: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum MathCommands {
+    /// Print the greatest common divisor of two numbers
+    Gcd { a: u64, b: u64 },
+    /// Print the least common multiple of two numbers
+    Lcm { a: u64, b: u64 },
+    /// Print every prime up to and including a limit (at most 10,000,000)
+    Primes { limit: u64 },
+}
+
+#[derive(Subcommand)]
+enum StringCommands {
+    /// Reverse a string, or each line of stdin when the text is "-"
+    Reverse { text: String },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Some(command) => run(command),
+        None => menu(),
+    };
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(command: Commands) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Commands::Date { command } => match command {
+            DateCommands::Diff { date1, date2 } => println!("{}", date_utils::date_difference_days(&date1, &date2)?),
+            DateCommands::Add { date, days } => println!("{}", date_utils::checked_add_days(&date, days)?),
+            DateCommands::Weekday { date } => println!("{}", date_utils::day_of_week(&date)?),
+            DateCommands::Leap { year } => println!("{}", date_utils::is_leap_year(year)),
+        },
+        Commands::File { command } => match command {
+            FileCommands::Stats { path } => file_stats(&path)?,
+        },
+        Commands::Math { command } => match command {
+            MathCommands::Gcd { a, b } => println!("{}", math_utils::gcd(a, b)),
+            MathCommands::Lcm { a, b } => {
+                let lcm = math_utils::checked_lcm(a, b)
+                    .ok_or_else(|| format!("the least common multiple of {} and {} is too large", a, b))?;
+                println!("{}", lcm);
+            },
+            MathCommands::Primes { limit } => {
+                if limit > MAX_PRIMES_LIMIT {
+                    return Err(format!("limit {} is too large (the maximum is {})", limit, MAX_PRIMES_LIMIT).into());
+                }
+                let primes: Vec<String> = math_utils::primes_up_to(limit)
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect();
+                println!("{}", primes.join(" "));
+            },
+        },
+        Commands::String { command } => match command {
+            StringCommands::Reverse { text } => {
+                for line in input_lines(text) {
+                    println!("{}", string_utils::reverse_string(&line?));
+                }
+            },
+        },
+        Commands::Watch { path, command } => watch(&path, &command)?,
+    }
+    Ok(())
+}
+
+/// Parses the arguments after "--" as a cli-utils subcommand, exiting with
+/// clap's usage message if they are invalid
+fn parse_subcommand(args: &[String]) -> Result<Commands, Box<dyn std::error::Error>> {
+    let cli = Cli::try_parse_from(std::iter::once("cli-utils").chain(args.iter().map(String::as_str)))
+        .unwrap_or_else(|err| err.exit());
+    cli.command.ok_or_else(|| "watch needs a subcommand to run".into())
+}
+
+/// Runs the subcommand once, then again each time the file's modification time changes.
+/// Errors from the subcommand are reported without stopping the watch.
+fn watch(path: &Path, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate the subcommand up front so typos fail immediately
+    parse_subcommand(args)?;
+    let mut last_modified = file_io_utils::modified_time(path)?;
+    loop {
+        if let Err(err) = run(parse_subcommand(args)?) {
+            eprintln!("Error: {}", err);
+        }
+        loop {
+            std::thread::sleep(WATCH_POLL_INTERVAL);
+            // The file may briefly disappear while an editor replaces it
+            if let Ok(modified) = file_io_utils::modified_time(path) {
+                if modified != last_modified {
+                    last_modified = modified;
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Checks that a command line argument is a valid YYYY-MM-DD date
+fn parse_date_arg(arg: &str) -> Result<String, String> {
+    if date_utils::validate_date_format(arg, "%Y-%m-%d") {
+        Ok(arg.to_string())
+    } else {
+        Err("expected a valid date in YYYY-MM-DD format".to_string())
+    }
+}
+
+/// Returns the text as a single line, or the lines of stdin when the text is "-"
+fn input_lines(text: String) -> Box<dyn Iterator<Item = io::Result<String>>> {
+    if text == "-" {
+        Box::new(read_stdin_lines())
+    } else {
+        Box::new(std::iter::once(Ok(text)))
+    }
+}
+
+fn file_stats(path: &Path) -> io::Result<()> {
+    let content = file_io_utils::read_file_to_string(path)?;
+    println!("Lines: {}", file_io_utils::count_lines(path)?);
+    println!("Words: {}", string_utils::word_count(&content));
+    println!("Characters: {}", content.chars().count());
+    println!("Top words:");
+    for (word, count) in string_utils::most_common_words(&content, 5) {
+        println!("  {}: {}", word, count);
+    }
+    Ok(())
+}
+
+fn menu() -> Result<(), Box<dyn std::error::Error>> {
     println!("Welcome to CLI Utils!");
     println!("This is a utility library with math, string, date, and file operations.");
     println!();
@@ -23,7 +241,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "2" => string_demo(),
             "3" => date_demo()?,
             "4" => file_demo()?,
-            "5" => interactive_mode(),
+            "5" => interactive_mode(&mut io::stdin().lock(), &mut io::stdout())?,
             "6" => {
                 println!("Goodbye!");
                 break;
@@ -75,7 +293,7 @@ fn file_demo() -> Result<(), Box<dyn std::error::Error>> {
     let read_content = file_io_utils::read_file_to_string(temp_file)?;
     println!("File content: {}", read_content.lines().next().unwrap_or(""));
     
-    println!("File size: {} bytes", file_io_utils::file_size(temp_file)?);
+    println!("File size: {}", file_io_utils::format_size(file_io_utils::file_size(temp_file)?));
     
     file_io_utils::delete_file(temp_file)?;
     println!("✓ Cleaned up demo file");
@@ -83,72 +301,147 @@ fn file_demo() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn interactive_mode() {
-    println!("=== Interactive Mode ===");
-    println!("Type 'exit' to return to main menu");
+fn interactive_mode<R: BufRead, W: Write>(reader: &mut R, out: &mut W) -> io::Result<()> {
+    writeln!(out, "=== Interactive Mode ===")?;
+    writeln!(out, "Type 'exit' to return to main menu")?;
+    
+    let mut history: Vec<String> = Vec::new();
     
     loop {
-        println!();
-        println!("Choose operation:");
-        println!("- factorial <number>");
-        println!("- prime <number>");
-        println!("- palindrome <text>");
-        println!("- reverse <text>");
-        println!("- exit");
-        print!("> ");
+        writeln!(out)?;
+        writeln!(out, "Choose operation:")?;
+        writeln!(out, "- factorial <number>")?;
+        writeln!(out, "- prime <number>")?;
+        writeln!(out, "- palindrome <text>")?;
+        writeln!(out, "- reverse <text>")?;
+        writeln!(out, "- history")?;
+        writeln!(out, "- !<n> (re-run command n from history)")?;
+        writeln!(out, "- exit")?;
+        write!(out, "> ")?;
+        out.flush()?;
         
-        let input = read_stdin();
-        let parts: Vec<&str> = input.trim().split_whitespace().collect();
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let mut input = line.trim().to_string();
         
-        if parts.is_empty() {
+        if input.is_empty() {
             continue;
         }
         
+        if let Some(index) = input.strip_prefix('!') {
+            let entry = index
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|i| history.get(i));
+            match entry {
+                Some(command) => {
+                    input = command.clone();
+                    writeln!(out, "{}", input)?;
+                },
+                None => {
+                    writeln!(out, "No such command in history: {}", input)?;
+                    continue;
+                },
+            }
+        }
+        
+        history.push(input.clone());
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        
         match parts[0] {
             "exit" => break,
+            "history" => {
+                for (i, command) in history.iter().enumerate() {
+                    writeln!(out, "{:>4}  {}", i + 1, command)?;
+                }
+            },
             "factorial" => {
                 if parts.len() != 2 {
-                    println!("Usage: factorial <number>");
+                    writeln!(out, "Usage: factorial <number>")?;
                     continue;
                 }
                 if let Ok(n) = parts[1].parse::<u64>() {
                     if n <= 20 {
-                        println!("Factorial of {}: {}", n, math_utils::factorial(n));
+                        writeln!(out, "Factorial of {}: {}", n, math_utils::factorial(n))?;
                     } else {
-                        println!("Number too large (max 20)");
+                        writeln!(out, "Number too large (max 20)")?;
                     }
                 } else {
-                    println!("Invalid number");
+                    writeln!(out, "Invalid number")?;
                 }
             },
             "prime" => {
                 if parts.len() != 2 {
-                    println!("Usage: prime <number>");
+                    writeln!(out, "Usage: prime <number>")?;
                     continue;
                 }
                 if let Ok(n) = parts[1].parse::<u64>() {
-                    println!("Is {} prime? {}", n, math_utils::is_prime(n));
+                    writeln!(out, "Is {} prime? {}", n, math_utils::is_prime(n))?;
                 } else {
-                    println!("Invalid number");
+                    writeln!(out, "Invalid number")?;
                 }
             },
             "palindrome" => {
                 if parts.len() < 2 {
-                    println!("Usage: palindrome <text>");
+                    writeln!(out, "Usage: palindrome <text>")?;
                     continue;
                 }
                 let text = parts[1..].join(" ");
-                println!("Is '{}' a palindrome? {}", text, string_utils::is_palindrome(&text));
+                writeln!(out, "Is '{}' a palindrome? {}", text, string_utils::is_palindrome(&text))?;
             },
             "reverse" => {
                 if parts.len() < 2 {
-                    println!("Usage: reverse <text>");
+                    writeln!(out, "Usage: reverse <text>")?;
                     continue;
                 }
                 let text = parts[1..].join(" ");
-                println!("Reverse of '{}': {}", text, string_utils::reverse_string(&text));
+                writeln!(out, "Reverse of '{}': {}", text, string_utils::reverse_string(&text))?;
             },
-            _ => println!("Unknown command. Try factorial, prime, palindrome, reverse, or exit"),
+            _ => writeln!(out, "Unknown command. Try factorial, prime, palindrome, reverse, history, or exit")?,
         }
     }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interactive_mode;
+    use std::io::Cursor;
+
+    fn run_script(script: &str) -> String {
+        let mut reader = Cursor::new(script);
+        let mut out = Vec::new();
+        interactive_mode(&mut reader, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_history_lists_commands() {
+        let output = run_script("factorial 5\nreverse abc\nhistory\nexit\n");
+        assert!(output.contains("   1  factorial 5\n   2  reverse abc\n   3  history\n"));
+    }
+
+    #[test]
+    fn test_history_rerun() {
+        let output = run_script("prime 17\n!1\nhistory\nexit\n");
+        assert_eq!(output.matches("Is 17 prime? true").count(), 2);
+        assert!(output.contains("   1  prime 17\n   2  prime 17\n   3  history\n"));
+    }
+
+    #[test]
+    fn test_history_rerun_out_of_range() {
+        let output = run_script("!3\nhistory\nexit\n");
+        assert!(output.contains("No such command in history: !3"));
+        assert!(output.contains("   1  history\n"));
+    }
+
+    #[test]
+    fn test_interactive_mode_stops_at_end_of_input() {
+        let output = run_script("factorial 3\n");
+        assert!(output.contains("Factorial of 3: 6"));
+    }
 }
\ No newline at end of file
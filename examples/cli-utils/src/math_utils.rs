@@ -1,9 +1,16 @@
 //! Mathematical utility functions
 //! 
 //! This module provides basic mathematical utility functions including:
-//! - Factorial calculation
+//! - Factorial calculation (panicking, checked, and saturating variants)
 //! - Greatest Common Divisor (GCD)
 //! - Prime number checking
+//! - Matrix operations (see the `matrix` submodule)
+
+pub mod matrix;
+
+use crate::string_utils::group_thousands;
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Rem};
 
 /// Calculates the factorial of a non-negative integer
 /// 
@@ -14,7 +21,8 @@
 /// The factorial of n (n!)
 /// 
 /// # Panics
-/// Panics if n is greater than 20 to prevent overflow
+/// Panics if n is greater than 20 to prevent overflow. Use `checked_factorial`
+/// to get `None` instead, or `factorial_saturating` to get `u64::MAX`.
 /// 
 /// # Examples
 /// ```
@@ -27,14 +35,55 @@ pub fn factorial(n: u64) -> u64 {
         panic!("Factorial input too large (max 20 to prevent overflow)");
     }
     
-    match n {
-        0 | 1 => 1,
-        _ => n * factorial(n - 1),
-    }
+    (2..=n).product()
+}
+
+/// Calculates the factorial of a non-negative integer, returning `None` on overflow
+/// 
+/// This is the non-panicking counterpart of `factorial`.
+/// 
+/// # Arguments
+/// * `n` - A non-negative integer
+/// 
+/// # Returns
+/// `Some(n!)`, or `None` if the result does not fit in a `u64`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::checked_factorial;
+/// assert_eq!(checked_factorial(5), Some(120));
+/// assert_eq!(checked_factorial(21), None);
+/// ```
+pub fn checked_factorial(n: u64) -> Option<u64> {
+    (2..=n).try_fold(1u64, |acc, i| acc.checked_mul(i))
+}
+
+/// Calculates the factorial of a non-negative integer, saturating at `u64::MAX` on overflow
+/// 
+/// This is the non-panicking counterpart of `factorial` for callers that
+/// prefer a clamped value over an `Option`.
+/// 
+/// # Arguments
+/// * `n` - A non-negative integer
+/// 
+/// # Returns
+/// n!, or `u64::MAX` if the result does not fit in a `u64`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::factorial_saturating;
+/// assert_eq!(factorial_saturating(5), 120);
+/// assert_eq!(factorial_saturating(100), u64::MAX);
+/// ```
+pub fn factorial_saturating(n: u64) -> u64 {
+    checked_factorial(n).unwrap_or(u64::MAX)
 }
 
 /// Calculates the Greatest Common Divisor (GCD) of two integers using Euclid's algorithm
 /// 
+/// This is `gcd_generic` fixed to `u64`, so untyped literal arguments like
+/// `gcd(48, 18)` still compile.
+/// 
 /// # Arguments
 /// * `a` - First integer
 /// * `b` - Second integer
@@ -48,17 +97,15 @@ pub fn factorial(n: u64) -> u64 {
 /// assert_eq!(gcd(48, 18), 6);
 /// assert_eq!(gcd(17, 19), 1);
 /// ```
-pub fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let temp = b;
-        b = a % b;
-        a = temp;
-    }
-    a
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_generic(a, b)
 }
 
 /// Checks if a number is prime
 /// 
+/// This is `is_prime_generic` fixed to `u64`, so untyped literal arguments
+/// still compile.
+/// 
 /// # Arguments
 /// * `n` - The number to check
 /// 
@@ -73,26 +120,166 @@ pub fn gcd(mut a: u64, mut b: u64) -> u64 {
 /// assert_eq!(is_prime(1), false);
 /// ```
 pub fn is_prime(n: u64) -> bool {
-    if n < 2 {
+    is_prime_generic(n)
+}
+
+/// Calculates the least common multiple (LCM) of two integers
+/// 
+/// This is `lcm_generic` fixed to `u64`, so untyped literal arguments still
+/// compile.
+/// 
+/// # Arguments
+/// * `a` - First integer
+/// * `b` - Second integer
+/// 
+/// # Returns
+/// The LCM of a and b
+/// 
+/// # Panics
+/// Panics in debug builds if the LCM doesn't fit in a `u64`. Use
+/// `checked_lcm` to get `None` instead.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(7, 9), 63);
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    lcm_generic(a, b)
+}
+
+/// Calculates the least common multiple (LCM) of two integers, returning
+/// `None` if it doesn't fit in a `u64`
+/// 
+/// # Arguments
+/// * `a` - First integer
+/// * `b` - Second integer
+/// 
+/// # Returns
+/// The LCM of a and b, or `None` on overflow
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::checked_lcm;
+/// assert_eq!(checked_lcm(4, 6), Some(12));
+/// assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+/// ```
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        Some(0)
+    } else {
+        (a / gcd(a, b)).checked_mul(b)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Unsigned integer types accepted by the generic number theory helpers
+/// (`gcd_generic`, `lcm_generic` and `is_prime_generic`)
+/// 
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64`,
+/// `u128` and `usize`.
+pub trait UnsignedInt:
+    sealed::Sealed
+    + Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+}
+
+macro_rules! impl_unsigned_int {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl UnsignedInt for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TWO: Self = 2;
+            }
+        )*
+    };
+}
+
+impl_unsigned_int!(u8, u16, u32, u64, u128, usize);
+
+/// Calculates the GCD of two integers of any unsigned type
+/// 
+/// `gcd` calls this for `u64`. Untyped literal arguments need a suffix
+/// (e.g. `48u32`) so the type can be inferred.
+/// 
+/// # Arguments
+/// * `a` - First integer
+/// * `b` - Second integer
+/// 
+/// # Returns
+/// The GCD of a and b
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::gcd_generic;
+/// assert_eq!(gcd_generic(48u32, 18), 6);
+/// assert_eq!(gcd_generic(17u8, 19), 1);
+/// ```
+pub fn gcd_generic<T: UnsignedInt>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Checks if a number of any unsigned type is prime
+/// 
+/// `is_prime` calls this for `u64`.
+/// 
+/// # Arguments
+/// * `n` - The number to check
+/// 
+/// # Returns
+/// `true` if the number is prime, `false` otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::is_prime_generic;
+/// assert!(is_prime_generic(65521u16));
+/// assert!(!is_prime_generic(4u32));
+/// assert!(!is_prime_generic(1u8));
+/// ```
+pub fn is_prime_generic<T: UnsignedInt>(n: T) -> bool {
+    if n < T::TWO {
         return false;
     }
-    if n == 2 {
+    if n == T::TWO {
         return true;
     }
-    if n % 2 == 0 {
+    if n % T::TWO == T::ZERO {
         return false;
     }
     
-    let sqrt_n = (n as f64).sqrt() as u64;
-    for i in (3..=sqrt_n).step_by(2) {
-        if n % i == 0 {
+    let mut i = T::TWO + T::ONE;
+    while i <= n / i {
+        if n % i == T::ZERO {
             return false;
         }
+        i = i + T::TWO;
     }
     true
 }
 
-/// Calculates the least common multiple (LCM) of two integers
+/// Calculates the LCM of two integers of any unsigned type
+/// 
+/// `lcm` calls this for `u64`.
 /// 
 /// # Arguments
 /// * `a` - First integer
@@ -103,62 +290,1266 @@ pub fn is_prime(n: u64) -> bool {
 /// 
 /// # Examples
 /// ```
-/// use cli_utils::math_utils::lcm;
-/// assert_eq!(lcm(4, 6), 12);
-/// assert_eq!(lcm(7, 9), 63);
+/// use cli_utils::math_utils::lcm_generic;
+/// assert_eq!(lcm_generic(4u32, 6), 12);
+/// assert_eq!(lcm_generic(7u8, 9), 63);
 /// ```
-pub fn lcm(a: u64, b: u64) -> u64 {
-    if a == 0 || b == 0 {
-        0
+pub fn lcm_generic<T: UnsignedInt>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        T::ZERO
     } else {
-        (a * b) / gcd(a, b)
+        a / gcd_generic(a, b) * b
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Spells out a non-negative integer in English words
+/// 
+/// Supports the full `u64` range (up to the quintillions).
+/// 
+/// # Arguments
+/// * `n` - The number to spell out
+/// 
+/// # Returns
+/// The number written in English words
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::to_words;
+/// assert_eq!(to_words(1234), "one thousand two hundred thirty-four");
+/// assert_eq!(to_words(0), "zero");
+/// ```
+pub fn to_words(n: u64) -> String {
+    const ONES: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        "ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen",
+        "seventeen", "eighteen", "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    const SCALES: [&str; 7] = [
+        "", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+    ];
 
-    #[test]
-    fn test_factorial() {
-        assert_eq!(factorial(0), 1);
-        assert_eq!(factorial(1), 1);
-        assert_eq!(factorial(5), 120);
-        assert_eq!(factorial(6), 720);
+    fn below_thousand(n: u64) -> String {
+        let mut words = Vec::new();
+        if n >= 100 {
+            words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+        }
+        let rest = n % 100;
+        if rest >= 20 {
+            let tens = TENS[(rest / 10) as usize];
+            match rest % 10 {
+                0 => words.push(tens.to_string()),
+                ones => words.push(format!("{}-{}", tens, ONES[ones as usize])),
+            }
+        } else if rest > 0 {
+            words.push(ONES[rest as usize].to_string());
+        }
+        words.join(" ")
     }
 
-    #[test]
-    #[should_panic(expected = "Factorial input too large")]
-    fn test_factorial_overflow() {
-        factorial(25);
+    if n == 0 {
+        return ONES[0].to_string();
     }
 
-    #[test]
-    fn test_gcd() {
-        assert_eq!(gcd(48, 18), 6);
-        assert_eq!(gcd(17, 19), 1);
-        assert_eq!(gcd(100, 25), 25);
-        assert_eq!(gcd(0, 5), 5);
-        assert_eq!(gcd(5, 0), 5);
+    let mut groups = Vec::new();
+    let mut remaining = n;
+    let mut scale = 0;
+    while remaining > 0 {
+        let group = remaining % 1000;
+        if group > 0 {
+            let words = below_thousand(group);
+            groups.push(match SCALES[scale] {
+                "" => words,
+                scale_name => format!("{} {}", words, scale_name),
+            });
+        }
+        remaining /= 1000;
+        scale += 1;
     }
+    groups.reverse();
+    groups.join(" ")
+}
 
-    #[test]
-    fn test_is_prime() {
-        assert_eq!(is_prime(1), false);
-        assert_eq!(is_prime(2), true);
-        assert_eq!(is_prime(3), true);
-        assert_eq!(is_prime(4), false);
-        assert_eq!(is_prime(17), true);
-        assert_eq!(is_prime(25), false);
-        assert_eq!(is_prime(97), true);
+/// Calculates what percentage one value is of another
+/// 
+/// # Arguments
+/// * `part` - The portion of the whole
+/// * `whole` - The total amount
+/// 
+/// # Returns
+/// `part / whole * 100`, or `None` if `whole` is zero
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::percentage;
+/// assert_eq!(percentage(17.0, 40.0), Some(42.5));
+/// assert_eq!(percentage(1.0, 0.0), None);
+/// ```
+pub fn percentage(part: f64, whole: f64) -> Option<f64> {
+    if whole == 0.0 {
+        None
+    } else {
+        Some(part / whole * 100.0)
     }
+}
 
-    #[test]
-    fn test_lcm() {
-        assert_eq!(lcm(4, 6), 12);
-        assert_eq!(lcm(7, 9), 63);
-        assert_eq!(lcm(12, 18), 36);
-        assert_eq!(lcm(0, 5), 0);
-        assert_eq!(lcm(5, 0), 0);
+/// Formats a percentage value with a fixed number of decimal places
+/// 
+/// # Arguments
+/// * `value` - The percentage value (e.g., 42.5 for 42.5%)
+/// * `decimals` - The number of decimal places to round to
+/// 
+/// # Returns
+/// The formatted percentage string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::format_percentage;
+/// assert_eq!(format_percentage(42.5, 1), "42.5%");
+/// assert_eq!(format_percentage(33.3333, 0), "33%");
+/// ```
+pub fn format_percentage(value: f64, decimals: usize) -> String {
+    format!("{:.*}%", decimals, value)
+}
+
+/// Calculates the nth term of the recurrence `a(k) = a(k-1) + a(k-2)` for the given seeds
+/// 
+/// With seeds 0 and 1 this yields the Fibonacci numbers; with seeds 2 and 1
+/// it yields the Lucas numbers.
+/// 
+/// # Arguments
+/// * `a0` - The term at index 0
+/// * `a1` - The term at index 1
+/// * `n` - The index of the term to compute
+/// 
+/// # Returns
+/// `Some(a(n))`, or `None` if the term does not fit in a `u64`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::linear_recurrence;
+/// assert_eq!(linear_recurrence(0, 1, 10), Some(55));
+/// assert_eq!(linear_recurrence(2, 1, 10), Some(123));
+/// ```
+pub fn linear_recurrence(a0: u64, a1: u64, n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(a0);
+    }
+    let (mut prev, mut current) = (a0, a1);
+    for _ in 1..n {
+        let next = prev.checked_add(current)?;
+        prev = current;
+        current = next;
+    }
+    Some(current)
+}
+
+/// Calculates the nth Fibonacci number, where `fibonacci(0) == 0` and `fibonacci(1) == 1`
+/// 
+/// # Arguments
+/// * `n` - The index of the Fibonacci number
+/// 
+/// # Returns
+/// `Some(F(n))`, or `None` if the result does not fit in a `u64`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::fibonacci;
+/// assert_eq!(fibonacci(10), Some(55));
+/// assert_eq!(fibonacci(94), None);
+/// ```
+pub fn fibonacci(n: u64) -> Option<u64> {
+    linear_recurrence(0, 1, n)
+}
+
+/// Finds all prime numbers up to and including a limit using the Sieve of Eratosthenes
+/// 
+/// # Arguments
+/// * `limit` - The largest number to consider
+/// 
+/// # Returns
+/// The primes less than or equal to `limit`, in ascending order
+/// 
+/// # Panics
+/// Panics if `limit + 1` doesn't fit in a `usize`. The sieve uses one byte
+/// per number up to `limit`, so callers taking the limit from user input
+/// should cap it first.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::primes_up_to;
+/// assert_eq!(primes_up_to(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+pub fn primes_up_to(limit: u64) -> Vec<u64> {
+    if limit < 2 {
+        return Vec::new();
+    }
+    let sieve_len = usize::try_from(limit)
+        .ok()
+        .and_then(|limit| limit.checked_add(1))
+        .expect("limit is too large to sieve");
+    let mut is_composite = vec![false; sieve_len];
+    let mut primes = Vec::new();
+    for i in 2..sieve_len {
+        if is_composite[i] {
+            continue;
+        }
+        primes.push(i as u64);
+        // Multiples below i * i were already marked by smaller primes
+        if let Some(square) = i.checked_mul(i) {
+            for multiple in (square..sieve_len).step_by(i) {
+                is_composite[multiple] = true;
+            }
+        }
+    }
+    primes
+}
+
+/// Formats an amount of money given in integer cents as a currency string
+/// 
+/// Working in integer cents avoids floating-point rounding errors. The whole
+/// part is grouped into thousands with commas and negative amounts are
+/// prefixed with a minus sign before the symbol.
+/// 
+/// # Arguments
+/// * `cents` - The amount in cents (can be negative)
+/// * `symbol` - The currency symbol to prefix (e.g., "$")
+/// 
+/// # Returns
+/// The formatted currency string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::format_currency;
+/// assert_eq!(format_currency(123456, "$"), "$1,234.56");
+/// assert_eq!(format_currency(-100, "$"), "-$1.00");
+/// ```
+pub fn format_currency(cents: i64, symbol: &str) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    let abs = cents.unsigned_abs();
+    let whole = group_thousands((abs / 100) as i64, ',');
+    format!("{}{}{}.{:02}", sign, symbol, whole, abs % 100)
+}
+
+/// Computes the prime factorization of a number by trial division
+/// 
+/// # Arguments
+/// * `n` - The number to factorize
+/// 
+/// # Returns
+/// The prime factors of n in ascending order, repeated according to their
+/// multiplicity. Returns an empty vector for 0 and 1.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::prime_factors;
+/// assert_eq!(prime_factors(60), vec![2, 2, 3, 5]);
+/// assert_eq!(prime_factors(13), vec![13]);
+/// ```
+pub fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+    let mut p = 2;
+    while p <= n / p {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Computes Euler's totient function φ(n)
+/// 
+/// φ(n) is the number of integers in `1..=n` that are coprime with n. It is
+/// computed with the product formula over the distinct prime factors of n.
+/// 
+/// # Arguments
+/// * `n` - A non-negative integer
+/// 
+/// # Returns
+/// φ(n), with φ(0) defined as 0
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::totient;
+/// assert_eq!(totient(9), 6);
+/// assert_eq!(totient(1), 1);
+/// ```
+pub fn totient(n: u64) -> u64 {
+    let mut factors = prime_factors(n);
+    factors.dedup();
+    factors.iter().fold(n, |acc, &p| acc / p * (p - 1))
+}
+
+/// Parses an unsigned integer written in decimal, hexadecimal, octal or binary
+/// 
+/// A `0x`, `0o` or `0b` prefix (in either case) selects the base, and
+/// underscores may be used anywhere as digit separators, as in Rust literals.
+/// 
+/// # Arguments
+/// * `s` - The string to parse
+/// 
+/// # Returns
+/// The parsed value, or `None` if the string is not a valid number or does
+/// not fit in a `u64`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::parse_int;
+/// assert_eq!(parse_int("0xFF"), Some(255));
+/// assert_eq!(parse_int("1_000"), Some(1000));
+/// assert_eq!(parse_int("0b1010"), Some(10));
+/// assert_eq!(parse_int("12ab"), None);
+/// ```
+pub fn parse_int(s: &str) -> Option<u64> {
+    let (radix, digits) = match s.get(..2) {
+        Some("0x" | "0X") => (16, &s[2..]),
+        Some("0o" | "0O") => (8, &s[2..]),
+        Some("0b" | "0B") => (2, &s[2..]),
+        _ => (10, s),
+    };
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+    u64::from_str_radix(&digits, radix).ok()
+}
+
+/// Computes a percentile of integer data using the nearest-rank method
+/// 
+/// The result is the smallest value such that at least `p` percent of the
+/// data is less than or equal to it, so it is always one of the input values.
+/// The 0th percentile is the minimum.
+/// 
+/// # Arguments
+/// * `data` - The values, in any order
+/// * `p` - The percentile to compute, between 0 and 100 inclusive
+/// 
+/// # Returns
+/// The percentile, or `None` if `data` is empty or `p` is outside `0..=100`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::percentile;
+/// let data = [15, 20, 35, 40, 50];
+/// assert_eq!(percentile(&data, 40.0), Some(20));
+/// assert_eq!(percentile(&data, 100.0), Some(50));
+/// assert_eq!(percentile(&[], 50.0), None);
+/// ```
+pub fn percentile(data: &[u64], p: f64) -> Option<u64> {
+    if data.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable();
+    let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.max(1) - 1])
+}
+
+/// Computes the median of integer data using the nearest-rank method
+/// 
+/// This is `percentile(data, 50.0)`: for an even number of values it returns
+/// the lower of the two middle values rather than their average.
+/// 
+/// # Arguments
+/// * `data` - The values, in any order
+/// 
+/// # Returns
+/// The median, or `None` if `data` is empty
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::median_u64;
+/// assert_eq!(median_u64(&[3, 1, 2]), Some(2));
+/// assert_eq!(median_u64(&[4, 1, 3, 2]), Some(2));
+/// ```
+pub fn median_u64(data: &[u64]) -> Option<u64> {
+    percentile(data, 50.0)
+}
+
+/// Checks whether a number is a perfect square
+/// 
+/// Uses the exact integer square root, so it stays correct for large values
+/// where a floating-point square root would round.
+/// 
+/// # Arguments
+/// * `n` - The number to check
+/// 
+/// # Returns
+/// true if n is the square of an integer, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::is_perfect_square;
+/// assert!(is_perfect_square(144));
+/// assert!(!is_perfect_square(145));
+/// ```
+pub fn is_perfect_square(n: u64) -> bool {
+    let root = n.isqrt();
+    root * root == n
+}
+
+/// Computes the integer cube root of a number (the largest r with r³ <= n)
+fn icbrt(n: u64) -> u64 {
+    // The float estimate may be off by one for large n, so correct it exactly
+    let mut root = (n as f64).cbrt() as u64;
+    while root.checked_pow(3).is_none_or(|cube| cube > n) {
+        root -= 1;
+    }
+    while (root + 1).checked_pow(3).is_some_and(|cube| cube <= n) {
+        root += 1;
+    }
+    root
+}
+
+/// Checks whether a number is a perfect cube
+/// 
+/// Uses an exact integer cube root, so it stays correct for large values
+/// where a floating-point cube root would round.
+/// 
+/// # Arguments
+/// * `n` - The number to check
+/// 
+/// # Returns
+/// true if n is the cube of an integer, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::is_perfect_cube;
+/// assert!(is_perfect_cube(27));
+/// assert!(!is_perfect_cube(28));
+/// ```
+pub fn is_perfect_cube(n: u64) -> bool {
+    icbrt(n).pow(3) == n
+}
+
+/// Calculates the weighted arithmetic mean of a set of values
+/// 
+/// # Arguments
+/// * `values` - The values to average
+/// * `weights` - The weight of each value, in the same order as `values`
+/// 
+/// # Returns
+/// The weighted mean, or `None` if the slices differ in length or the weights
+/// sum to zero (which includes empty input)
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::weighted_mean;
+/// // Exam worth 60%, coursework worth 40%
+/// assert_eq!(weighted_mean(&[80.0, 90.0], &[0.6, 0.4]), Some(84.0));
+/// assert_eq!(weighted_mean(&[80.0], &[0.6, 0.4]), None);
+/// ```
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Option<f64> {
+    if values.len() != weights.len() {
+        return None;
+    }
+    let total_weight: f64 = weights.iter().sum();
+    if total_weight == 0.0 {
+        return None;
+    }
+    let weighted_sum: f64 = values.iter().zip(weights).map(|(value, weight)| value * weight).sum();
+    Some(weighted_sum / total_weight)
+}
+
+/// Reduces a fraction to lowest terms with a positive denominator
+/// 
+/// # Arguments
+/// * `num` - The numerator
+/// * `den` - The denominator
+/// 
+/// # Returns
+/// The reduced `(numerator, denominator)`, or `None` if the denominator is 0
+/// or the sign normalization overflows (only possible with `i64::MIN`)
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::reduce_fraction;
+/// assert_eq!(reduce_fraction(6, -8), Some((-3, 4)));
+/// assert_eq!(reduce_fraction(0, 5), Some((0, 1)));
+/// assert_eq!(reduce_fraction(1, 0), None);
+/// ```
+pub fn reduce_fraction(num: i64, den: i64) -> Option<(i64, i64)> {
+    if den == 0 {
+        return None;
+    }
+    let divisor = gcd(num.unsigned_abs(), den.unsigned_abs());
+    // The divisor only exceeds i64::MAX when both values are i64::MIN
+    let (num, den) = match i64::try_from(divisor) {
+        Ok(divisor) => (num / divisor, den / divisor),
+        Err(_) => (1, 1),
+    };
+    if den < 0 {
+        Some((num.checked_neg()?, den.checked_neg()?))
+    } else {
+        Some((num, den))
+    }
+}
+
+/// Expands a rational number into its continued-fraction coefficients
+/// 
+/// Uses the Euclidean algorithm, so the expansion `[a0; a1, a2, ...]` is the
+/// sequence of quotients and is always finite.
+/// 
+/// # Arguments
+/// * `num` - The numerator
+/// * `den` - The denominator
+/// 
+/// # Returns
+/// The coefficients, starting with the integer part, or an empty vector if
+/// `den` is 0
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::continued_fraction;
+/// assert_eq!(continued_fraction(415, 93), vec![4, 2, 6, 7]);
+/// assert_eq!(continued_fraction(10, 5), vec![2]);
+/// ```
+pub fn continued_fraction(mut num: u64, mut den: u64) -> Vec<u64> {
+    let mut coefficients = Vec::new();
+    while den != 0 {
+        coefficients.push(num / den);
+        (num, den) = (den, num % den);
+    }
+    coefficients
+}
+
+/// Sums a slice of integers, checking for overflow
+/// 
+/// # Arguments
+/// * `data` - The values to sum
+/// 
+/// # Returns
+/// The sum (0 for an empty slice), or `None` if it overflows a `u64`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::sum_u64;
+/// assert_eq!(sum_u64(&[1, 2, 3]), Some(6));
+/// assert_eq!(sum_u64(&[u64::MAX, 1]), None);
+/// ```
+pub fn sum_u64(data: &[u64]) -> Option<u64> {
+    data.iter().try_fold(0u64, |acc, &x| acc.checked_add(x))
+}
+
+/// Multiplies a slice of integers together, checking for overflow
+/// 
+/// # Arguments
+/// * `data` - The values to multiply
+/// 
+/// # Returns
+/// The product (1 for an empty slice), or `None` if it overflows a `u64`.
+/// A slice containing 0 always has a product of 0, however large the other
+/// values are.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::product_u64;
+/// assert_eq!(product_u64(&[2, 3, 4]), Some(24));
+/// assert_eq!(product_u64(&[u64::MAX, 2]), None);
+/// assert_eq!(product_u64(&[u64::MAX, 2, 0]), Some(0));
+/// ```
+pub fn product_u64(data: &[u64]) -> Option<u64> {
+    if data.contains(&0) {
+        return Some(0);
+    }
+    data.iter().try_fold(1u64, |acc, &x| acc.checked_mul(x))
+}
+
+/// Finds the smallest value in a slice of integers
+/// 
+/// # Arguments
+/// * `data` - The values to search
+/// 
+/// # Returns
+/// The minimum, or `None` if the slice is empty
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::min_u64;
+/// assert_eq!(min_u64(&[3, 1, 2]), Some(1));
+/// assert_eq!(min_u64(&[]), None);
+/// ```
+pub fn min_u64(data: &[u64]) -> Option<u64> {
+    data.iter().copied().min()
+}
+
+/// Finds the largest value in a slice of integers
+/// 
+/// # Arguments
+/// * `data` - The values to search
+/// 
+/// # Returns
+/// The maximum, or `None` if the slice is empty
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::max_u64;
+/// assert_eq!(max_u64(&[3, 1, 2]), Some(3));
+/// assert_eq!(max_u64(&[]), None);
+/// ```
+pub fn max_u64(data: &[u64]) -> Option<u64> {
+    data.iter().copied().max()
+}
+
+/// Summarizes a list of integers as comma-separated ranges, like "1-3,5,7-8"
+/// 
+/// The input is sorted and deduplicated first. Runs of two or more
+/// consecutive numbers are written as `start-end`; isolated numbers on
+/// their own.
+/// 
+/// # Arguments
+/// * `nums` - The numbers to summarize, in any order
+/// 
+/// # Returns
+/// The range summary, or an empty string for an empty slice
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::compress_ranges;
+/// assert_eq!(compress_ranges(&[1, 2, 3, 5, 7, 8]), "1-3,5,7-8");
+/// assert_eq!(compress_ranges(&[8, 7, 7, 1]), "1,7-8");
+/// ```
+pub fn compress_ranges(nums: &[u64]) -> String {
+    let mut sorted = nums.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut ranges: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < sorted.len() {
+        let start = sorted[i];
+        let mut end = start;
+        while i + 1 < sorted.len() && sorted[i + 1] == end + 1 {
+            i += 1;
+            end = sorted[i];
+        }
+        ranges.push(if start == end {
+            start.to_string()
+        } else {
+            format!("{}-{}", start, end)
+        });
+        i += 1;
+    }
+    ranges.join(",")
+}
+
+/// The most numbers `expand_ranges` will produce
+const EXPAND_RANGES_MAX_LEN: u64 = 10_000_000;
+
+/// Expands a range summary like "1-3,5,7-8" into the numbers it covers
+/// 
+/// This is the inverse of `compress_ranges`. Whitespace around each
+/// comma-separated item is ignored, and an empty string expands to no numbers.
+/// To guard against hostile input, summaries covering more than 10,000,000
+/// numbers in total are rejected.
+/// 
+/// # Arguments
+/// * `s` - The range summary to expand
+/// 
+/// # Returns
+/// The numbers in the order given, or an error describing the first
+/// malformed item or reversed range
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::expand_ranges;
+/// assert_eq!(expand_ranges("1-3,5,7-8"), Ok(vec![1, 2, 3, 5, 7, 8]));
+/// assert!(expand_ranges("5-3").is_err());
+/// ```
+pub fn expand_ranges(s: &str) -> Result<Vec<u64>, String> {
+    let mut nums = Vec::new();
+    if s.trim().is_empty() {
+        return Ok(nums);
+    }
+    let parse = |text: &str, item: &str| {
+        text.trim()
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid number '{}' in '{}'", text.trim(), item))
+    };
+    for item in s.split(',') {
+        let item = item.trim();
+        let (start, end) = match item.split_once('-') {
+            Some((start, end)) => (parse(start, item)?, parse(end, item)?),
+            None => {
+                let n = parse(item, item)?;
+                (n, n)
+            }
+        };
+        if start > end {
+            return Err(format!("Range '{}' is reversed", item));
+        }
+        // Capping before adding 1 keeps 0-18446744073709551615 from overflowing
+        let len = (end - start).min(EXPAND_RANGES_MAX_LEN) + 1;
+        if nums.len() as u64 + len > EXPAND_RANGES_MAX_LEN {
+            return Err(format!("Ranges expand to more than {} numbers", EXPAND_RANGES_MAX_LEN));
+        }
+        nums.extend(start..=end);
+    }
+    Ok(nums)
+}
+
+/// Parses a list of integers separated by commas and/or whitespace
+/// 
+/// Separators can be mixed and repeated, so "1, 2 3,,4" is accepted, which
+/// makes it suitable for number lists typed on the command line.
+/// 
+/// # Arguments
+/// * `s` - The list to parse
+/// 
+/// # Returns
+/// The numbers in the order given, or an error naming the first token that
+/// is not a valid non-negative integer
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::parse_number_list;
+/// assert_eq!(parse_number_list("1, 2 3,4"), Ok(vec![1, 2, 3, 4]));
+/// assert_eq!(parse_number_list("1,x"), Err("Invalid number 'x'".to_string()));
+/// ```
+pub fn parse_number_list(s: &str) -> Result<Vec<u64>, String> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid number '{}'", token))
+        })
+        .collect()
+}
+
+/// Binary searches a sorted slice with a comparator function
+/// 
+/// `f` is called with an element and returns how that element compares to
+/// the target, as with `slice::binary_search_by`. Unlike the standard library
+/// version, when several elements match this always returns the first one:
+/// the search keeps narrowing to the left half until only one candidate is
+/// left, then checks whether it matches.
+/// 
+/// # Arguments
+/// * `slice` - The slice to search, sorted consistently with `f`
+/// * `f` - Returns `Less` if the element is before the target, `Greater` if
+///   it is after, and `Equal` if it matches
+/// 
+/// # Returns
+/// The index of the first matching element, or `None` if nothing matches
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::math_utils::binary_search_by;
+/// let nums = [1, 3, 3, 3, 7];
+/// assert_eq!(binary_search_by(&nums, |n| n.cmp(&3)), Some(1));
+/// assert_eq!(binary_search_by(&nums, |n| n.cmp(&4)), None);
+/// ```
+pub fn binary_search_by<T, F: Fn(&T) -> Ordering>(slice: &[T], f: F) -> Option<usize> {
+    // Invariant: every element before `low` is Less, every element from
+    // `high` on is Equal or Greater
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if f(&slice[mid]) == Ordering::Less {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    match slice.get(low) {
+        Some(item) if f(item) == Ordering::Equal => Some(low),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), 1);
+        assert_eq!(factorial(1), 1);
+        assert_eq!(factorial(5), 120);
+        assert_eq!(factorial(6), 720);
+    }
+
+    #[test]
+    #[should_panic(expected = "Factorial input too large")]
+    fn test_factorial_overflow() {
+        factorial(25);
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(17, 19), 1);
+        assert_eq!(gcd(100, 25), 25);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn test_gcd_generic() {
+        let a: u32 = 48;
+        let b: u32 = 18;
+        assert_eq!(gcd_generic(a, b), 6u32);
+        assert_eq!(gcd_generic(48u64, 18u64), 6u64);
+        assert_eq!(gcd_generic(u64::MAX, 3), 3);
+        assert_eq!(gcd_generic(12u8, 8), 4);
+        assert_eq!(lcm_generic(4u32, 6), 12);
+        // a * b overflows but the LCM fits
+        assert_eq!(lcm_generic(200u8, 100), 200);
+        assert_eq!(lcm_generic(1u64 << 62, 1 << 62), 1 << 62);
+        assert!(is_prime_generic(65521u16));
+        assert!(is_prime_generic(18446744073709551557u64));
+        assert!(!is_prime_generic(u64::MAX));
+        // The u64 functions and the generic ones at other widths agree
+        for a in 0..50u32 {
+            for b in 0..50u32 {
+                assert_eq!(gcd(a as u64, b as u64), gcd_generic(a, b) as u64);
+                assert_eq!(lcm(a as u64, b as u64), lcm_generic(a, b) as u64);
+            }
+            assert_eq!(is_prime(a as u64), is_prime_generic(a as u8));
+        }
+    }
+
+    #[test]
+    fn test_is_prime() {
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(!is_prime(4));
+        assert!(is_prime(17));
+        assert!(!is_prime(25));
+        assert!(is_prime(97));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(7, 9), 63);
+        assert_eq!(lcm(12, 18), 36);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(5, 0), 0);
+    }
+
+    #[test]
+    fn test_checked_lcm() {
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(0, 5), Some(0));
+        assert_eq!(checked_lcm(5, 0), Some(0));
+        // a * b overflows but the LCM itself fits
+        assert_eq!(checked_lcm(1 << 62, 1 << 62), Some(1 << 62));
+        assert_eq!(checked_lcm(u64::MAX, 3), Some(u64::MAX));
+        assert_eq!(checked_lcm(4294967296, 4294967297), None);
+    }
+
+    #[test]
+    fn test_checked_factorial() {
+        assert_eq!(checked_factorial(0), Some(1));
+        assert_eq!(checked_factorial(20), Some(2432902008176640000));
+        assert_eq!(checked_factorial(21), None);
+        for n in 0..=20 {
+            assert_eq!(checked_factorial(n), Some(factorial(n)));
+        }
+    }
+
+    #[test]
+    fn test_factorial_saturating() {
+        assert_eq!(factorial_saturating(100), u64::MAX);
+        assert_eq!(factorial_saturating(21), u64::MAX);
+        for n in 0..=20 {
+            assert_eq!(factorial_saturating(n), factorial(n));
+        }
+    }
+
+    #[test]
+    fn test_to_words() {
+        assert_eq!(to_words(0), "zero");
+        assert_eq!(to_words(7), "seven");
+        assert_eq!(to_words(13), "thirteen");
+        assert_eq!(to_words(40), "forty");
+        assert_eq!(to_words(99), "ninety-nine");
+        assert_eq!(to_words(1234), "one thousand two hundred thirty-four");
+    }
+
+    #[test]
+    fn test_to_words_boundaries() {
+        assert_eq!(to_words(100), "one hundred");
+        assert_eq!(to_words(1000), "one thousand");
+        assert_eq!(to_words(1001), "one thousand one");
+        assert_eq!(to_words(1_000_000), "one million");
+        assert_eq!(to_words(1_000_000_001), "one billion one");
+        assert_eq!(to_words(2_500_000_110), "two billion five hundred million one hundred ten");
+        assert_eq!(
+            to_words(u64::MAX),
+            "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four trillion \
+             seventy-three billion seven hundred nine million five hundred fifty-one thousand six hundred fifteen"
+        );
+    }
+
+    #[test]
+    fn test_percentage() {
+        assert_eq!(percentage(17.0, 40.0), Some(42.5));
+        assert_eq!(percentage(0.0, 10.0), Some(0.0));
+        assert_eq!(percentage(30.0, 20.0), Some(150.0));
+        assert_eq!(percentage(5.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_format_percentage() {
+        assert_eq!(format_percentage(42.5, 1), "42.5%");
+        assert_eq!(format_percentage(2.0 / 3.0 * 100.0, 1), "66.7%");
+        assert_eq!(format_percentage(12.34, 1), "12.3%");
+        assert_eq!(format_percentage(50.0, 0), "50%");
+        assert_eq!(format_percentage(percentage(1.0, 8.0).unwrap(), 2), "12.50%");
+    }
+
+    #[test]
+    fn test_linear_recurrence_fibonacci_seeds() {
+        let expected = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+        for (n, value) in expected.iter().enumerate() {
+            assert_eq!(linear_recurrence(0, 1, n as u64), Some(*value));
+            assert_eq!(fibonacci(n as u64), Some(*value));
+        }
+        assert_eq!(fibonacci(93), Some(12200160415121876738));
+        assert_eq!(fibonacci(94), None);
+    }
+
+    #[test]
+    fn test_linear_recurrence_lucas_seeds() {
+        let expected = [2, 1, 3, 4, 7, 11, 18, 29, 47, 76, 123];
+        for (n, value) in expected.iter().enumerate() {
+            assert_eq!(linear_recurrence(2, 1, n as u64), Some(*value));
+        }
+        assert_eq!(linear_recurrence(u64::MAX, 1, 2), None);
+    }
+
+    #[test]
+    fn test_primes_up_to() {
+        assert_eq!(primes_up_to(0), Vec::<u64>::new());
+        assert_eq!(primes_up_to(1), Vec::<u64>::new());
+        assert_eq!(primes_up_to(2), vec![2]);
+        assert_eq!(primes_up_to(20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+        assert_eq!(primes_up_to(100).len(), 25);
+        assert!(primes_up_to(1000).into_iter().all(is_prime));
+    }
+
+    #[test]
+    fn test_format_currency() {
+        assert_eq!(format_currency(0, "$"), "$0.00");
+        assert_eq!(format_currency(5, "$"), "$0.05");
+        assert_eq!(format_currency(99999, "€"), "€999.99");
+        assert_eq!(format_currency(123456, "$"), "$1,234.56");
+        assert_eq!(format_currency(123456789012, "£"), "£1,234,567,890.12");
+    }
+
+    #[test]
+    fn test_format_currency_negative() {
+        assert_eq!(format_currency(-100, "$"), "-$1.00");
+        assert_eq!(format_currency(-7, "$"), "-$0.07");
+        assert_eq!(format_currency(-100000000, "$"), "-$1,000,000.00");
+        assert_eq!(format_currency(i64::MIN, "$"), "-$92,233,720,368,547,758.08");
+    }
+
+    #[test]
+    fn test_factorial_matches_recursive_definition() {
+        let mut expected = 1u64;
+        for n in 0..=20 {
+            if n > 0 {
+                expected *= n;
+            }
+            assert_eq!(factorial(n), expected);
+            assert_eq!(checked_factorial(n), Some(expected));
+        }
+        assert_eq!(factorial(20), 2_432_902_008_176_640_000);
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(0), Vec::<u64>::new());
+        assert_eq!(prime_factors(1), Vec::<u64>::new());
+        assert_eq!(prime_factors(2), vec![2]);
+        assert_eq!(prime_factors(360), vec![2, 2, 2, 3, 3, 5]);
+        assert_eq!(prime_factors(97), vec![97]);
+        assert_eq!(prime_factors(2 * 1_000_000_007), vec![2, 1_000_000_007]);
+    }
+
+    #[test]
+    fn test_totient() {
+        assert_eq!(totient(0), 0);
+        assert_eq!(totient(1), 1);
+        // Prime
+        assert_eq!(totient(13), 12);
+        // Prime powers
+        assert_eq!(totient(9), 6);
+        assert_eq!(totient(32), 16);
+        // Composites
+        assert_eq!(totient(36), 12);
+        assert_eq!(totient(100), 40);
+    }
+
+    #[test]
+    fn test_parse_int() {
+        // Decimal, with and without separators
+        assert_eq!(parse_int("42"), Some(42));
+        assert_eq!(parse_int("1_000"), Some(1000));
+        assert_eq!(parse_int("1_000_000"), Some(1_000_000));
+        // Base prefixes
+        assert_eq!(parse_int("0xff"), Some(255));
+        assert_eq!(parse_int("0XFF"), Some(255));
+        assert_eq!(parse_int("0xdead_beef"), Some(0xdead_beef));
+        assert_eq!(parse_int("0o755"), Some(0o755));
+        assert_eq!(parse_int("0b1010"), Some(10));
+        assert_eq!(parse_int("0b1111_0000"), Some(240));
+        assert_eq!(parse_int("0"), Some(0));
+        assert_eq!(parse_int("18446744073709551615"), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_parse_int_invalid() {
+        assert_eq!(parse_int(""), None);
+        assert_eq!(parse_int("_"), None);
+        assert_eq!(parse_int("0x"), None);
+        assert_eq!(parse_int("0b102"), None);
+        assert_eq!(parse_int("0o8"), None);
+        assert_eq!(parse_int("12ab"), None);
+        assert_eq!(parse_int("-5"), None);
+        assert_eq!(parse_int("+5"), None);
+        assert_eq!(parse_int(" 5"), None);
+        assert_eq!(parse_int("18446744073709551616"), None);
+        assert_eq!(parse_int("é"), None);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let data = [7, 1, 10, 3, 9, 2, 8, 4, 6, 5];
+        assert_eq!(percentile(&data, 50.0), Some(5));
+        assert_eq!(percentile(&data, 90.0), Some(9));
+        assert_eq!(percentile(&data, 100.0), Some(10));
+        assert_eq!(percentile(&data, 91.0), Some(10));
+        assert_eq!(percentile(&data, 0.0), Some(1));
+        assert_eq!(percentile(&[42], 75.0), Some(42));
+    }
+
+    #[test]
+    fn test_percentile_invalid() {
+        assert_eq!(percentile(&[], 50.0), None);
+        assert_eq!(percentile(&[1, 2, 3], -1.0), None);
+        assert_eq!(percentile(&[1, 2, 3], 100.5), None);
+        assert_eq!(percentile(&[1, 2, 3], f64::NAN), None);
+    }
+
+    #[test]
+    fn test_median_u64() {
+        assert_eq!(median_u64(&[5, 3, 1, 4, 2]), Some(3));
+        assert_eq!(median_u64(&[1, 2, 3, 4]), Some(2));
+        assert_eq!(median_u64(&[]), None);
+    }
+
+    #[test]
+    fn test_is_perfect_square() {
+        assert!(is_perfect_square(0));
+        assert!(is_perfect_square(1));
+        assert!(is_perfect_square(144));
+        assert!(!is_perfect_square(145));
+        assert!(!is_perfect_square(2));
+        // Near the top of the u64 range, where f64 can't tell neighbours apart
+        let big_root = 4_294_967_295u64;
+        assert!(is_perfect_square(big_root * big_root));
+        assert!(!is_perfect_square(big_root * big_root - 1));
+        assert!(!is_perfect_square(big_root * big_root + 1));
+        let root = 3_037_000_499u64;
+        assert!(is_perfect_square(root * root));
+        assert!(!is_perfect_square(root * root + 1));
+        assert!(!is_perfect_square(u64::MAX));
+    }
+
+    #[test]
+    fn test_is_perfect_cube() {
+        assert!(is_perfect_cube(0));
+        assert!(is_perfect_cube(1));
+        assert!(is_perfect_cube(27));
+        assert!(!is_perfect_cube(26));
+        assert!(!is_perfect_cube(28));
+        // 2642245 is the largest integer whose cube fits in a u64
+        let big_root = 2_642_245u64;
+        assert!(is_perfect_cube(big_root.pow(3)));
+        assert!(!is_perfect_cube(big_root.pow(3) - 1));
+        assert!(!is_perfect_cube(big_root.pow(3) + 1));
+        assert!(!is_perfect_cube(u64::MAX));
+        for root in 0..2000u64 {
+            assert!(is_perfect_cube(root.pow(3)));
+            assert_eq!(icbrt(root.pow(3) + 1), if root == 0 { 1 } else { root });
+        }
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let mean = weighted_mean(&[90.0, 80.0, 70.0], &[3.0, 2.0, 1.0]).unwrap();
+        assert!((mean - 83.333_333).abs() < 1e-5);
+        assert_eq!(weighted_mean(&[1.0, 2.0, 3.0], &[1.0, 1.0, 1.0]), Some(2.0));
+        assert_eq!(weighted_mean(&[5.0, 100.0], &[1.0, 0.0]), Some(5.0));
+    }
+
+    #[test]
+    fn test_weighted_mean_invalid() {
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0]), None);
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[0.0, 0.0]), None);
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, -1.0]), None);
+        assert_eq!(weighted_mean(&[], &[]), None);
+    }
+
+    #[test]
+    fn test_reduce_fraction() {
+        // Sign normalization
+        assert_eq!(reduce_fraction(6, -8), Some((-3, 4)));
+        assert_eq!(reduce_fraction(-6, -8), Some((3, 4)));
+        assert_eq!(reduce_fraction(-6, 8), Some((-3, 4)));
+        // Already reduced
+        assert_eq!(reduce_fraction(3, 4), Some((3, 4)));
+        assert_eq!(reduce_fraction(-7, 1), Some((-7, 1)));
+        assert_eq!(reduce_fraction(0, -5), Some((0, 1)));
+        assert_eq!(reduce_fraction(100, 25), Some((4, 1)));
+        // Zero denominator
+        assert_eq!(reduce_fraction(1, 0), None);
+        assert_eq!(reduce_fraction(0, 0), None);
+        // Extremes
+        assert_eq!(reduce_fraction(i64::MIN, i64::MIN), Some((1, 1)));
+        assert_eq!(reduce_fraction(i64::MIN, 2), Some((i64::MIN / 2, 1)));
+        assert_eq!(reduce_fraction(i64::MIN, -2), Some((1 << 62, 1)));
+        assert_eq!(reduce_fraction(i64::MIN, -1), None);
+        assert_eq!(reduce_fraction(1, i64::MIN), None);
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        assert_eq!(continued_fraction(415, 93), vec![4, 2, 6, 7]);
+        // 355/113, the famous approximation of pi
+        assert_eq!(continued_fraction(355, 113), vec![3, 7, 16]);
+        // Consecutive Fibonacci numbers expand to all ones
+        assert_eq!(continued_fraction(13, 8), vec![1, 1, 1, 1, 2]);
+        assert_eq!(continued_fraction(3, 7), vec![0, 2, 3]);
+        // Integers have a single coefficient
+        assert_eq!(continued_fraction(7, 1), vec![7]);
+        assert_eq!(continued_fraction(0, 5), vec![0]);
+        assert!(continued_fraction(5, 0).is_empty());
+    }
+
+    #[test]
+    fn test_sum_u64() {
+        assert_eq!(sum_u64(&[1, 2, 3, 4]), Some(10));
+        assert_eq!(sum_u64(&[]), Some(0));
+        assert_eq!(sum_u64(&[u64::MAX, 0]), Some(u64::MAX));
+        assert_eq!(sum_u64(&[u64::MAX, 1]), None);
+    }
+
+    #[test]
+    fn test_product_u64() {
+        assert_eq!(product_u64(&[2, 3, 4]), Some(24));
+        assert_eq!(product_u64(&[]), Some(1));
+        assert_eq!(product_u64(&[5, 0, u64::MAX]), Some(0));
+        // A zero after an intermediate overflow still gives 0
+        assert_eq!(product_u64(&[u64::MAX, 2, 0]), Some(0));
+        // 2^32 * 2^32 overflows
+        assert_eq!(product_u64(&[1 << 32, 1 << 32]), None);
+        assert_eq!(product_u64(&[1 << 32, 1 << 31]), Some(1 << 63));
+    }
+
+    #[test]
+    fn test_min_max_u64() {
+        let data = [7, 3, 9, 3, 8];
+        assert_eq!(min_u64(&data), Some(3));
+        assert_eq!(max_u64(&data), Some(9));
+        assert_eq!(min_u64(&[42]), Some(42));
+        assert_eq!(max_u64(&[42]), Some(42));
+        assert_eq!(min_u64(&[]), None);
+        assert_eq!(max_u64(&[]), None);
+    }
+
+    #[test]
+    fn test_compress_ranges() {
+        // Consecutive runs
+        assert_eq!(compress_ranges(&[1, 2, 3, 5, 7, 8]), "1-3,5,7-8");
+        assert_eq!(compress_ranges(&[10, 11, 12, 13]), "10-13");
+        // Singletons
+        assert_eq!(compress_ranges(&[1, 3, 5]), "1,3,5");
+        assert_eq!(compress_ranges(&[42]), "42");
+        // Unsorted input with duplicates
+        assert_eq!(compress_ranges(&[5, 3, 4, 4, 1, 3]), "1,3-5");
+        assert_eq!(compress_ranges(&[u64::MAX, u64::MAX - 1, 0]), format!("0,{}-{}", u64::MAX - 1, u64::MAX));
+        // Empty
+        assert_eq!(compress_ranges(&[]), "");
+    }
+
+    #[test]
+    fn test_expand_ranges() {
+        assert_eq!(expand_ranges("1-3,5,7-8"), Ok(vec![1, 2, 3, 5, 7, 8]));
+        assert_eq!(expand_ranges(" 1 - 2 , 4 "), Ok(vec![1, 2, 4]));
+        assert_eq!(expand_ranges("4-4"), Ok(vec![4]));
+        assert_eq!(expand_ranges(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_expand_ranges_errors() {
+        assert_eq!(expand_ranges("5-3"), Err("Range '5-3' is reversed".to_string()));
+        assert_eq!(expand_ranges("1,x,3"), Err("Invalid number 'x' in 'x'".to_string()));
+        assert_eq!(expand_ranges("1-"), Err("Invalid number '' in '1-'".to_string()));
+        assert!(expand_ranges("1,,2").is_err());
+        assert!(expand_ranges("1-2-3").is_err());
+        assert!(expand_ranges("-1").is_err());
+    }
+
+    #[test]
+    fn test_expand_ranges_too_large() {
+        let error = Err("Ranges expand to more than 10000000 numbers".to_string());
+        assert_eq!(expand_ranges("0-18446744073709551615"), error);
+        assert_eq!(expand_ranges("1-6000000,10000001-16000000"), error);
+        assert_eq!(expand_ranges("1-10000000,5"), error);
+        assert_eq!(expand_ranges("1-10000000").unwrap().len(), 10_000_000);
+    }
+
+    #[test]
+    fn test_expand_compress_round_trip() {
+        for nums in [vec![1, 2, 3, 5, 7, 8], vec![0], vec![2, 4, 6], vec![10, 11, 12, 20, 21], vec![]] {
+            let summary = compress_ranges(&nums);
+            assert_eq!(expand_ranges(&summary).unwrap(), nums);
+            assert_eq!(compress_ranges(&expand_ranges(&summary).unwrap()), summary);
+        }
+    }
+
+    #[test]
+    fn test_parse_number_list() {
+        assert_eq!(parse_number_list("1,2,3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_number_list("4 5\t6"), Ok(vec![4, 5, 6]));
+        assert_eq!(parse_number_list(" 1, 2  3,,4\n"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(parse_number_list(""), Ok(vec![]));
+        assert_eq!(parse_number_list("1, two, 3"), Err("Invalid number 'two'".to_string()));
+        assert_eq!(parse_number_list("1 -2"), Err("Invalid number '-2'".to_string()));
+    }
+
+    #[test]
+    fn test_binary_search_by() {
+        let nums = [1, 2, 4, 4, 4, 4, 8, 9];
+        // Found, always the first match
+        assert_eq!(binary_search_by(&nums, |n| n.cmp(&1)), Some(0));
+        assert_eq!(binary_search_by(&nums, |n| n.cmp(&4)), Some(2));
+        assert_eq!(binary_search_by(&nums, |n| n.cmp(&9)), Some(7));
+        // Not found
+        assert_eq!(binary_search_by(&nums, |n| n.cmp(&0)), None);
+        assert_eq!(binary_search_by(&nums, |n| n.cmp(&5)), None);
+        assert_eq!(binary_search_by(&nums, |n| n.cmp(&10)), None);
+        // Empty slice
+        assert_eq!(binary_search_by(&[] as &[i32], |n| n.cmp(&1)), None);
+        // Searching by a key
+        let words = ["apple", "banana", "blueberry", "cherry"];
+        assert_eq!(binary_search_by(&words, |w| w.as_bytes()[0].cmp(&b'b')), Some(1));
     }
 }
\ No newline at end of file
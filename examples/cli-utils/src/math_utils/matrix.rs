@@ -0,0 +1,332 @@
+//! A small dense matrix type over `f64`
+//! 
+//! This module provides:
+//! - Construction from row vectors
+//! - Matrix multiplication
+//! - Transposition
+//! - Determinants via LU decomposition
+//! 
+//! # Examples:
+//! ```
+//! use cli_utils::math_utils::matrix::Matrix;
+//! let m = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+//! assert_eq!(m.determinant().unwrap(), -2.0);
+//! ```
+
+use std::fmt;
+
+/// Errors returned by matrix operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The rows passed to a constructor were empty or of unequal length
+    InvalidShape,
+    /// The operands' dimensions are incompatible for the operation
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// The operation requires a square matrix
+    NotSquare { rows: usize, cols: usize },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::InvalidShape => write!(f, "rows must be non-empty and of equal length"),
+            MatrixError::DimensionMismatch { left, right } => write!(
+                f,
+                "dimension mismatch: {}x{} and {}x{}",
+                left.0, left.1, right.0, right.1
+            ),
+            MatrixError::NotSquare { rows, cols } => write!(f, "matrix is not square: {}x{}", rows, cols),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+/// A dense, row-major matrix of `f64` values
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawMatrix"))]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+/// The serialized form of a `Matrix`, which is validated by `from_rows`
+/// before it becomes one
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawMatrix> for Matrix {
+    type Error = MatrixError;
+
+    fn try_from(raw: RawMatrix) -> Result<Self, Self::Error> {
+        if raw.cols == 0 || raw.rows.checked_mul(raw.cols) != Some(raw.data.len()) {
+            return Err(MatrixError::InvalidShape);
+        }
+        Matrix::from_rows(raw.data.chunks(raw.cols).map(<[f64]>::to_vec).collect())
+    }
+}
+
+impl Matrix {
+    /// Creates a matrix from a list of rows
+    /// 
+    /// # Examples
+    /// ```
+    /// use cli_utils::math_utils::matrix::Matrix;
+    /// let m = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+    /// assert_eq!((m.rows(), m.cols()), (1, 3));
+    /// assert!(Matrix::from_rows(vec![vec![1.0], vec![2.0, 3.0]]).is_err());
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<f64>>) -> Result<Self, MatrixError> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if cols == 0 || rows.iter().any(|row| row.len() != cols) {
+            return Err(MatrixError::InvalidShape);
+        }
+        Ok(Self {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Creates an `n` x `n` identity matrix
+    /// 
+    /// # Panics
+    /// Panics if `n` is zero, since a matrix always has at least one row and
+    /// one column (as `from_rows` requires).
+    pub fn identity(n: usize) -> Self {
+        assert!(n > 0, "identity matrix size must be greater than zero");
+        let mut data = vec![0.0; n * n];
+        for i in 0..n {
+            data[i * n + i] = 1.0;
+        }
+        Self { rows: n, cols: n, data }
+    }
+
+    /// Returns the number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the element at `row`, `col`, or `None` if out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row < self.rows && col < self.cols {
+            Some(self.data[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    /// Multiplies this matrix by another
+    /// 
+    /// # Examples
+    /// ```
+    /// use cli_utils::math_utils::matrix::Matrix;
+    /// let a = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(a.multiply(&Matrix::identity(2)).unwrap(), a);
+    /// ```
+    pub fn multiply(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+        let mut data = vec![0.0; self.rows * other.cols];
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                data[i * other.cols + j] = (0..self.cols)
+                    .map(|k| self.data[i * self.cols + k] * other.data[k * other.cols + j])
+                    .sum();
+            }
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: other.cols,
+            data,
+        })
+    }
+
+    /// Returns the transpose of this matrix
+    pub fn transpose(&self) -> Matrix {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self.data[i * self.cols + j]);
+            }
+        }
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+
+    /// Calculates the determinant using LU decomposition with partial pivoting
+    /// 
+    /// # Examples
+    /// ```
+    /// use cli_utils::math_utils::matrix::Matrix;
+    /// let m = Matrix::from_rows(vec![vec![4.0, 6.0], vec![3.0, 8.0]]).unwrap();
+    /// assert_eq!(m.determinant().unwrap(), 14.0);
+    /// ```
+    pub fn determinant(&self) -> Result<f64, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        let n = self.rows;
+        let mut lu = self.data.clone();
+        let mut det = 1.0;
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| lu[a * n + col].abs().total_cmp(&lu[b * n + col].abs()))
+                .unwrap_or(col);
+            if lu[pivot * n + col] == 0.0 {
+                return Ok(0.0);
+            }
+            if pivot != col {
+                for k in 0..n {
+                    lu.swap(pivot * n + k, col * n + k);
+                }
+                det = -det;
+            }
+            let diagonal = lu[col * n + col];
+            det *= diagonal;
+            for row in col + 1..n {
+                let factor = lu[row * n + col] / diagonal;
+                for k in col..n {
+                    lu[row * n + k] -= factor * lu[col * n + k];
+                }
+            }
+        }
+        Ok(det)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let m = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        assert_eq!((m.rows(), m.cols()), (3, 2));
+        assert_eq!(m.get(2, 1), Some(6.0));
+        assert_eq!(m.get(3, 0), None);
+        assert_eq!(Matrix::from_rows(vec![]), Err(MatrixError::InvalidShape));
+        assert_eq!(Matrix::from_rows(vec![vec![1.0], vec![]]), Err(MatrixError::InvalidShape));
+    }
+
+    #[test]
+    fn test_determinant_2x2() {
+        let m = Matrix::from_rows(vec![vec![4.0, 6.0], vec![3.0, 8.0]]).unwrap();
+        assert!(approx_eq(m.determinant().unwrap(), 14.0));
+        let singular = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert!(approx_eq(singular.determinant().unwrap(), 0.0));
+    }
+
+    #[test]
+    fn test_determinant_3x3() {
+        let m = Matrix::from_rows(vec![
+            vec![0.0, 2.0, 1.0],
+            vec![3.0, -1.0, 2.0],
+            vec![1.0, 1.0, 4.0],
+        ])
+        .unwrap();
+        assert!(approx_eq(m.determinant().unwrap(), -16.0));
+        assert!(approx_eq(Matrix::identity(4).determinant().unwrap(), 1.0));
+    }
+
+    #[test]
+    fn test_multiply_3x3() {
+        let a = Matrix::from_rows(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ])
+        .unwrap();
+        let b = Matrix::from_rows(vec![
+            vec![9.0, 8.0, 7.0],
+            vec![6.0, 5.0, 4.0],
+            vec![3.0, 2.0, 1.0],
+        ])
+        .unwrap();
+        let expected = Matrix::from_rows(vec![
+            vec![30.0, 24.0, 18.0],
+            vec![84.0, 69.0, 54.0],
+            vec![138.0, 114.0, 90.0],
+        ])
+        .unwrap();
+        assert_eq!(a.multiply(&b).unwrap(), expected);
+        assert_eq!(a.multiply(&Matrix::identity(3)).unwrap(), a);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let expected = Matrix::from_rows(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]).unwrap();
+        assert_eq!(m.transpose(), expected);
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let a = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        let b = Matrix::from_rows(vec![vec![1.0, 2.0]]).unwrap();
+        let err = a.multiply(&b).unwrap_err();
+        assert_eq!(err, MatrixError::DimensionMismatch { left: (1, 3), right: (1, 2) });
+        assert_eq!(err.to_string(), "dimension mismatch: 1x3 and 1x2");
+        assert_eq!(a.determinant(), Err(MatrixError::NotSquare { rows: 1, cols: 3 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "identity matrix size must be greater than zero")]
+    fn test_identity_zero_panics() {
+        Matrix::identity(0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let m = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"rows":3,"cols":2,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#);
+        let decoded: Matrix = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, m);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_rejects_invalid_shape() {
+        for json in [
+            r#"{"rows":3,"cols":3,"data":[]}"#,
+            r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#,
+            r#"{"rows":0,"cols":0,"data":[]}"#,
+            r#"{"rows":18446744073709551615,"cols":2,"data":[1.0,2.0]}"#,
+        ] {
+            assert!(serde_json::from_str::<Matrix>(json).is_err(), "{}", json);
+        }
+    }
+}
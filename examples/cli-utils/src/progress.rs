@@ -0,0 +1,134 @@
+//! A simple text progress bar for long-running operations in the CLI.
+//! 
+//! Functions with a progress callback, like
+//! `file_io_utils::sha256_file_with_progress`, can drive the bar directly.
+//! # Examples:
+//! ```
+//! use cli_utils::ProgressBar;
+//! use cli_utils::file_io_utils::{file_size, read_chunked, sha256_file_with_progress};
+//! # let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+//! let mut progress = ProgressBar::new(file_size(path).unwrap());
+//! let digest = sha256_file_with_progress(path, |n| progress.inc(n)).unwrap();
+//! progress.finish();
+//!
+//! // Or report progress from your own chunked processing
+//! let mut progress = ProgressBar::new(file_size(path).unwrap());
+//! read_chunked(path, 8192, |chunk| {
+//!     // ... process the chunk ...
+//!     progress.inc(chunk.len() as u64);
+//!     Ok(())
+//! }).unwrap();
+//! progress.finish();
+//! ```
+
+use std::io::{self, Write};
+
+/// The number of characters between the brackets of the bar.
+const BAR_WIDTH: usize = 20;
+
+/// Renders a `[#####     ] 50%` progress bar, redrawing it in place with `\r`.
+///
+/// By default the bar is drawn to stderr so it doesn't mix with a command's
+/// output. It is only redrawn when its text changes, so calling `inc` for
+/// every small chunk of work is cheap. Errors writing the bar are ignored.
+pub struct ProgressBar<W: Write = io::Stderr> {
+    total: u64,
+    current: u64,
+    last_drawn: Option<String>,
+    out: W,
+}
+
+impl ProgressBar {
+    /// Creates a progress bar for `total` units of work, drawn to stderr.
+    pub fn new(total: u64) -> Self {
+        Self::with_writer(total, io::stderr())
+    }
+}
+
+impl<W: Write> ProgressBar<W> {
+    /// Creates a progress bar for `total` units of work, drawn to `out`.
+    pub fn with_writer(total: u64, out: W) -> Self {
+        Self {
+            total,
+            current: 0,
+            last_drawn: None,
+            out,
+        }
+    }
+
+    /// Records `n` more units of completed work and redraws the bar.
+    /// Progress beyond the total is shown as 100%.
+    pub fn inc(&mut self, n: u64) {
+        self.current = self.current.saturating_add(n).min(self.total);
+        self.draw();
+    }
+
+    /// Returns the bar's current text, like "[##########          ] 50%".
+    /// A bar with a total of zero is always complete.
+    pub fn render(&self) -> String {
+        let fraction = if self.total == 0 {
+            1.0
+        } else {
+            self.current as f64 / self.total as f64
+        };
+        let filled = (fraction * BAR_WIDTH as f64) as usize;
+        format!(
+            "[{}{}] {}%",
+            "#".repeat(filled),
+            " ".repeat(BAR_WIDTH - filled),
+            (fraction * 100.0) as u64
+        )
+    }
+
+    /// Marks all work as done, draws the completed bar and ends its line.
+    pub fn finish(&mut self) {
+        self.current = self.total;
+        self.draw();
+        let _ = writeln!(self.out);
+        let _ = self.out.flush();
+    }
+
+    fn draw(&mut self) {
+        let text = self.render();
+        if self.last_drawn.as_ref() == Some(&text) {
+            return;
+        }
+        let _ = write!(self.out, "\r{}", text);
+        let _ = self.out.flush();
+        self.last_drawn = Some(text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut progress = ProgressBar::with_writer(4, Vec::new());
+        assert_eq!(progress.render(), "[                    ] 0%");
+        progress.inc(2);
+        assert_eq!(progress.render(), "[##########          ] 50%");
+        progress.inc(10);
+        assert_eq!(progress.render(), "[####################] 100%");
+        assert_eq!(ProgressBar::with_writer(0, Vec::new()).render(), "[####################] 100%");
+    }
+
+    #[test]
+    fn test_increments_reach_completion() {
+        let mut buffer = Vec::new();
+        let mut progress = ProgressBar::with_writer(10, &mut buffer);
+        for _ in 0..10 {
+            progress.inc(1);
+        }
+        progress.finish();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let frames: Vec<&str> = output.split('\r').filter(|frame| !frame.is_empty()).collect();
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[0], "[##                  ] 10%");
+        assert_eq!(frames[4], "[##########          ] 50%");
+        // finish() doesn't redraw an unchanged bar, only ends the line
+        assert_eq!(frames[9], "[####################] 100%\n");
+    }
+}
@@ -0,0 +1,111 @@
+//! A simple stopwatch for timing operations in the CLI.
+//! # Examples:
+//! ```
+//! use cli_utils::Stopwatch;
+//! let mut stopwatch = Stopwatch::start();
+//! // ... do some work ...
+//! let first = stopwatch.lap();
+//! println!("Took {}", stopwatch.elapsed_human());
+//! ```
+
+use std::time::{Duration, Instant};
+
+/// Measures elapsed time since it was started, with support for laps.
+pub struct Stopwatch {
+    started: Instant,
+    last_lap: Instant,
+}
+
+impl Stopwatch {
+    /// Starts a new stopwatch.
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Self {
+            started: now,
+            last_lap: now,
+        }
+    }
+
+    /// Returns the time elapsed since the stopwatch was started.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns the time elapsed since the stopwatch was started, formatted like "1.23s" or "450ms".
+    pub fn elapsed_human(&self) -> String {
+        format_duration_human(self.elapsed())
+    }
+
+    /// Returns the time since the previous lap (or since the start for the first lap)
+    /// and begins a new lap.
+    pub fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let lap = now - self.last_lap;
+        self.last_lap = now;
+        lap
+    }
+}
+
+/// Formats a duration for display, picking the unit by magnitude.
+/// Durations under a millisecond are shown in microseconds, under a second in
+/// whole milliseconds, and otherwise in seconds with two decimal places.
+/// # Examples:
+/// ```
+/// use cli_utils::stopwatch::format_duration_human;
+/// use std::time::Duration;
+/// assert_eq!(format_duration_human(Duration::from_millis(450)), "450ms");
+/// assert_eq!(format_duration_human(Duration::from_millis(1234)), "1.23s");
+/// ```
+pub fn format_duration_human(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{}µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn test_elapsed_is_monotonic() {
+        let stopwatch = Stopwatch::start();
+        let first = stopwatch.elapsed();
+        sleep(Duration::from_millis(5));
+        let second = stopwatch.elapsed();
+        assert!(second >= first);
+        assert!(second >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_lap() {
+        let mut stopwatch = Stopwatch::start();
+        sleep(Duration::from_millis(5));
+        let first = stopwatch.lap();
+        let second = stopwatch.lap();
+        assert!(first >= Duration::from_millis(5));
+        assert!(second < first);
+        assert!(stopwatch.elapsed() >= first + second);
+    }
+
+    #[test]
+    fn test_format_duration_human() {
+        assert_eq!(format_duration_human(Duration::from_micros(250)), "250µs");
+        assert_eq!(format_duration_human(Duration::from_millis(1)), "1ms");
+        assert_eq!(format_duration_human(Duration::from_millis(450)), "450ms");
+        assert_eq!(format_duration_human(Duration::from_millis(999)), "999ms");
+        assert_eq!(format_duration_human(Duration::from_secs(1)), "1.00s");
+        assert_eq!(format_duration_human(Duration::from_millis(1234)), "1.23s");
+        assert_eq!(format_duration_human(Duration::from_secs(90)), "90.00s");
+    }
+
+    #[test]
+    fn test_elapsed_human() {
+        let stopwatch = Stopwatch::start();
+        assert!(stopwatch.elapsed_human().ends_with('s'));
+    }
+}
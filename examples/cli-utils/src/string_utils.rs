@@ -5,6 +5,10 @@
 //! - Character counting
 //! - String reversal
 //! - Case conversion utilities
+//! - Word frequency analysis
+
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Checks if a string is a palindrome (reads the same forwards and backwards)
 /// 
@@ -149,67 +153,2142 @@ pub fn is_alphabetic(s: &str) -> bool {
     !s.is_empty() && s.chars().all(|c| c.is_alphabetic())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Indents every non-empty line of a multi-line string by a number of spaces
+/// 
+/// Lines that are empty or contain only whitespace are left untouched.
+/// 
+/// # Arguments
+/// * `s` - The string to indent
+/// * `spaces` - The number of spaces to prefix each line with
+/// 
+/// # Returns
+/// A new string with each non-empty line indented
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::indent;
+/// assert_eq!(indent("fn main() {\n}", 4), "    fn main() {\n    }");
+/// assert_eq!(indent("a\n\nb", 2), "  a\n\n  b");
+/// ```
+pub fn indent(s: &str, spaces: usize) -> String {
+    let padding = " ".repeat(spaces);
+    s.split('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                line.to_string()
+            } else {
+                format!("{}{}", padding, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    #[test]
-    fn test_is_palindrome() {
-        assert_eq!(is_palindrome("racecar"), true);
-        assert_eq!(is_palindrome("hello"), false);
-        assert_eq!(is_palindrome("A man a plan a canal Panama"), true);
-        assert_eq!(is_palindrome("race a car"), false);
-        assert_eq!(is_palindrome(""), true);
-        assert_eq!(is_palindrome("a"), true);
+/// Removes the longest common leading whitespace from every line of a string
+/// 
+/// Whitespace-only lines are ignored when computing the common prefix and
+/// are emptied in the output.
+/// 
+/// # Arguments
+/// * `s` - The string to dedent
+/// 
+/// # Returns
+/// A new string with the common indentation removed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::dedent;
+/// assert_eq!(dedent("    a\n      b\n    c"), "a\n  b\nc");
+/// ```
+pub fn dedent(s: &str) -> String {
+    let mut common: Option<&str> = None;
+    for line in s.split('\n').filter(|line| !line.trim().is_empty()) {
+        let leading = &line[..line.len() - line.trim_start().len()];
+        common = Some(match common {
+            None => leading,
+            Some(prefix) => {
+                let shared = prefix
+                    .char_indices()
+                    .zip(leading.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map_or(prefix.len().min(leading.len()), |((i, _), _)| i);
+                &prefix[..shared]
+            }
+        });
     }
+    let common = common.unwrap_or("");
 
-    #[test]
-    fn test_count_char() {
-        assert_eq!(count_char("hello world", 'l'), 3);
-        assert_eq!(count_char("rust programming", 'r'), 3);
-        assert_eq!(count_char("hello", 'x'), 0);
-        assert_eq!(count_char("", 'a'), 0);
+    s.split('\n')
+        .map(|line| {
+            if line.trim().is_empty() {
+                ""
+            } else {
+                &line[common.len()..]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Formats a number with its English ordinal suffix
+/// 
+/// # Arguments
+/// * `n` - The number to format
+/// 
+/// # Returns
+/// The number followed by "st", "nd", "rd" or "th"
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::ordinal;
+/// assert_eq!(ordinal(1), "1st");
+/// assert_eq!(ordinal(12), "12th");
+/// assert_eq!(ordinal(23), "23rd");
+/// ```
+pub fn ordinal(n: u64) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Counts how often each word appears in a string
+/// 
+/// Words are whitespace-delimited and compared case-insensitively.
+/// 
+/// # Arguments
+/// * `s` - The string to analyze
+/// 
+/// # Returns
+/// A map from each lowercased word to its number of occurrences
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::word_frequency;
+/// let freq = word_frequency("The cat and the hat");
+/// assert_eq!(freq["the"], 2);
+/// assert_eq!(freq["cat"], 1);
+/// ```
+pub fn word_frequency(s: &str) -> HashMap<String, usize> {
+    let mut freq = HashMap::new();
+    for word in s.split_whitespace() {
+        *freq.entry(word.to_lowercase()).or_insert(0) += 1;
     }
+    freq
+}
 
-    #[test]
-    fn test_reverse_string() {
-        assert_eq!(reverse_string("hello"), "olleh");
-        assert_eq!(reverse_string("rust"), "tsur");
-        assert_eq!(reverse_string(""), "");
-        assert_eq!(reverse_string("a"), "a");
+/// Returns the most frequent words in a string
+/// 
+/// Results are ordered by descending count, with ties broken alphabetically
+/// so the output is stable.
+/// 
+/// # Arguments
+/// * `s` - The string to analyze
+/// * `n` - The maximum number of words to return
+/// 
+/// # Returns
+/// Up to `n` (word, count) pairs
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::most_common_words;
+/// let top = most_common_words("b a b c a b", 2);
+/// assert_eq!(top, vec![("b".to_string(), 3), ("a".to_string(), 2)]);
+/// ```
+pub fn most_common_words(s: &str, n: usize) -> Vec<(String, usize)> {
+    let mut words: Vec<(String, usize)> = word_frequency(s).into_iter().collect();
+    words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    words.truncate(n);
+    words
+}
+
+/// Counts the number of distinct words in a string
+/// 
+/// Words are whitespace-delimited and compared case-insensitively.
+/// 
+/// # Arguments
+/// * `s` - The string to count distinct words in
+/// 
+/// # Returns
+/// The number of unique words
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::distinct_word_count;
+/// assert_eq!(distinct_word_count("the cat the dog"), 3);
+/// ```
+pub fn distinct_word_count(s: &str) -> usize {
+    word_frequency(s).len()
+}
+
+/// Checks if all characters of one string appear in another in the same order
+/// 
+/// The characters do not need to be contiguous, which makes this useful as a
+/// simple fuzzy filter.
+/// 
+/// # Arguments
+/// * `needle` - The characters to look for
+/// * `haystack` - The string to search in
+/// 
+/// # Returns
+/// `true` if `needle` is a subsequence of `haystack`, `false` otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::is_subsequence;
+/// assert!(is_subsequence("ace", "abcde"));
+/// assert!(!is_subsequence("aec", "abcde"));
+/// ```
+pub fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+/// Turns a file name into a human-readable display name
+/// 
+/// Strips the final extension, replaces underscores and hyphens with spaces,
+/// and converts the result to title case.
+/// 
+/// # Arguments
+/// * `name` - The file name to convert
+/// 
+/// # Returns
+/// A display name for the file
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::humanize_filename;
+/// assert_eq!(humanize_filename("my_report_final.txt"), "My Report Final");
+/// assert_eq!(humanize_filename("release-notes.md"), "Release Notes");
+/// ```
+pub fn humanize_filename(name: &str) -> String {
+    let stem = match name.rfind('.') {
+        Some(index) if index > 0 => &name[..index],
+        _ => name,
+    };
+    to_title_case(&stem.replace(['_', '-'], " "))
+}
+
+/// Masks the middle of a string, keeping a number of characters visible at each end
+/// 
+/// Useful for redacting sensitive values such as card numbers in logs.
+/// 
+/// # Arguments
+/// * `s` - The string to mask
+/// * `visible_prefix` - The number of leading characters to keep
+/// * `visible_suffix` - The number of trailing characters to keep
+/// * `mask_char` - The character to replace hidden characters with
+/// 
+/// # Returns
+/// The masked string, or the original if the visible counts cover the whole string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::mask;
+/// assert_eq!(mask("1234567812343456", 4, 4, '*'), "1234********3456");
+/// assert_eq!(mask("abc", 2, 2, '*'), "abc");
+/// ```
+pub fn mask(s: &str, visible_prefix: usize, visible_suffix: usize, mask_char: char) -> String {
+    let len = s.chars().count();
+    if visible_prefix.saturating_add(visible_suffix) >= len {
+        return s.to_string();
     }
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if i < visible_prefix || i >= len - visible_suffix {
+                c
+            } else {
+                mask_char
+            }
+        })
+        .collect()
+}
 
-    #[test]
-    fn test_to_title_case() {
-        assert_eq!(to_title_case("hello world"), "Hello World");
-        assert_eq!(to_title_case("rust programming"), "Rust Programming");
-        assert_eq!(to_title_case("HELLO WORLD"), "Hello World");
-        assert_eq!(to_title_case(""), "");
+/// Wraps text into lines of at most `width` characters, breaking on word boundaries
+/// 
+/// Whitespace between words is collapsed to a single space. A word longer
+/// than `width` is placed on its own line rather than split.
+/// 
+/// # Arguments
+/// * `s` - The text to wrap
+/// * `width` - The maximum line width in characters
+/// 
+/// # Returns
+/// The wrapped lines
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::wrap_text;
+/// assert_eq!(wrap_text("the quick brown fox", 10), vec!["the quick", "brown fox"]);
+/// ```
+pub fn wrap_text(s: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    for word in s.split_whitespace() {
+        let word_len = word.chars().count();
+        if current_len > 0 && current_len + 1 + word_len > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.push_str(word);
+        current_len += word_len;
+    }
+    if current_len > 0 {
+        lines.push(current);
     }
+    lines
+}
 
-    #[test]
-    fn test_remove_whitespace() {
-        assert_eq!(remove_whitespace("hello world"), "helloworld");
-        assert_eq!(remove_whitespace("  rust  programming  "), "rustprogramming");
-        assert_eq!(remove_whitespace(""), "");
-        assert_eq!(remove_whitespace("   "), "");
+/// Rewraps each paragraph of a text to the given width, preserving paragraph breaks
+/// 
+/// Paragraphs are separated by one or more blank lines; in the output they are
+/// separated by exactly one blank line.
+/// 
+/// # Arguments
+/// * `s` - The text to reflow
+/// * `width` - The maximum line width in characters
+/// 
+/// # Returns
+/// The reflowed text
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::reflow;
+/// let text = "one two\nthree four\n\nfive six seven";
+/// assert_eq!(reflow(text, 9), "one two\nthree\nfour\n\nfive six\nseven");
+/// ```
+pub fn reflow(s: &str, width: usize) -> String {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+    for line in s.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
     }
 
-    #[test]
-    fn test_word_count() {
-        assert_eq!(word_count("hello world"), 2);
-        assert_eq!(word_count("  rust  programming  language  "), 3);
-        assert_eq!(word_count(""), 0);
-        assert_eq!(word_count("   "), 0);
-        assert_eq!(word_count("single"), 1);
+    paragraphs
+        .iter()
+        .map(|paragraph| wrap_text(paragraph, width).join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Counts how often each character appears in a string
+/// 
+/// # Arguments
+/// * `s` - The string to analyze
+/// 
+/// # Returns
+/// A map from each character to its number of occurrences
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::char_frequency;
+/// let freq = char_frequency("hello");
+/// assert_eq!(freq[&'l'], 2);
+/// assert_eq!(freq[&'h'], 1);
+/// ```
+pub fn char_frequency(s: &str) -> HashMap<char, usize> {
+    let mut freq = HashMap::new();
+    for c in s.chars() {
+        *freq.entry(c).or_insert(0) += 1;
     }
+    freq
+}
 
-    #[test]
-    fn test_is_alphabetic() {
-        assert_eq!(is_alphabetic("hello"), true);
-        assert_eq!(is_alphabetic("hello123"), false);
-        assert_eq!(is_alphabetic(""), false);
-        assert_eq!(is_alphabetic("hello world"), false); // contains space
-        assert_eq!(is_alphabetic("HelloWorld"), true);
+/// Counts how often each character appears in a string, in a stable display order
+/// 
+/// # Arguments
+/// * `s` - The string to analyze
+/// 
+/// # Returns
+/// (character, count) pairs sorted by descending count, then ascending character
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::frequency_sorted;
+/// assert_eq!(frequency_sorted("abbccc"), vec![('c', 3), ('b', 2), ('a', 1)]);
+/// ```
+pub fn frequency_sorted(s: &str) -> Vec<(char, usize)> {
+    let mut pairs: Vec<(char, usize)> = char_frequency(s).into_iter().collect();
+    pairs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    pairs
+}
+
+/// Compresses a string using run-length encoding
+/// 
+/// Each run of a repeated character is written as the character followed by
+/// the run length, so "aaabbc" becomes "a3b2c1".
+/// 
+/// Because counts are written as decimal digits, input containing ASCII
+/// digits cannot be decoded unambiguously and will not round-trip through
+/// `rle_decode`.
+/// 
+/// # Arguments
+/// * `s` - The string to encode
+/// 
+/// # Returns
+/// The run-length encoded string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::rle_encode;
+/// assert_eq!(rle_encode("aaabbc"), "a3b2c1");
+/// ```
+pub fn rle_encode(s: &str) -> String {
+    let mut encoded = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        encoded.push(c);
+        encoded.push_str(&count.to_string());
+    }
+    encoded
+}
+
+/// The longest output `rle_decode` will produce, in characters
+const RLE_DECODE_MAX_LEN: usize = 10_000_000;
+
+/// Decompresses a run-length encoded string produced by `rle_encode`
+/// 
+/// To guard against hostile input, decoding fails if the output would be
+/// longer than 10,000,000 characters.
+/// 
+/// # Arguments
+/// * `s` - The encoded string, a sequence of characters each followed by a count
+/// 
+/// # Returns
+/// The decoded string, or an error describing why the input is malformed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::rle_decode;
+/// assert_eq!(rle_decode("a3b2c1").unwrap(), "aaabbc");
+/// assert!(rle_decode("a3b").is_err());
+/// ```
+pub fn rle_decode(s: &str) -> Result<String, String> {
+    let mut decoded = String::new();
+    let mut decoded_len: usize = 0;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            return Err(format!("Expected a character but found digit '{}'", c));
+        }
+        let mut digits = String::new();
+        while let Some(d) = chars.next_if(|d| d.is_ascii_digit()) {
+            digits.push(d);
+        }
+        if digits.is_empty() {
+            return Err(format!("Missing count after '{}'", c));
+        }
+        let count: usize = digits
+            .parse()
+            .map_err(|_| format!("Invalid count '{}' after '{}'", digits, c))?;
+        if count == 0 {
+            return Err(format!("Count after '{}' must be greater than zero", c));
+        }
+        decoded_len = decoded_len.saturating_add(count);
+        if decoded_len > RLE_DECODE_MAX_LEN {
+            return Err(format!(
+                "Decoded output would exceed the maximum of {} characters",
+                RLE_DECODE_MAX_LEN
+            ));
+        }
+        decoded.extend(std::iter::repeat_n(c, count));
+    }
+    Ok(decoded)
+}
+
+/// Groups the digits of an integer into thousands with a separator
+/// 
+/// # Arguments
+/// * `n` - The number to format (can be negative)
+/// * `separator` - The character to insert between groups of three digits
+/// 
+/// # Returns
+/// The grouped number, with any minus sign kept in front
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::group_thousands;
+/// assert_eq!(group_thousands(1234567, ','), "1,234,567");
+/// assert_eq!(group_thousands(-1000, ' '), "-1 000");
+/// ```
+pub fn group_thousands(n: i64, separator: char) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    if n < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Continues a CRC-32 computation with more data, so large inputs can be checksummed in pieces
+pub(crate) fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &byte in data {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Calculates the CRC-32 checksum of a byte slice
+/// 
+/// Uses the standard CRC-32 (IEEE 802.3) polynomial, as used by zip and PNG.
+/// This is a fast checksum for detecting changes, not a cryptographic hash.
+/// 
+/// # Arguments
+/// * `data` - The bytes to checksum
+/// 
+/// # Returns
+/// The CRC-32 checksum
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::crc32_bytes;
+/// assert_eq!(crc32_bytes(b"123456789"), 0xCBF43926);
+/// ```
+pub fn crc32_bytes(data: &[u8]) -> u32 {
+    crc32_update(0, data)
+}
+
+/// Converts a string to title case, also capitalizing after hyphens and apostrophes
+/// 
+/// Unlike `to_title_case`, the letter following a hyphen or apostrophe is
+/// capitalized too, which suits names and compound words. Note that this also
+/// applies to contractions, so "don't" becomes "Don'T". Whitespace is preserved.
+/// 
+/// # Arguments
+/// * `s` - The string to convert
+/// 
+/// # Returns
+/// A new string in title case
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::to_title_case_hyphenated;
+/// assert_eq!(to_title_case_hyphenated("o'brien mother-in-law"), "O'Brien Mother-In-Law");
+/// ```
+pub fn to_title_case_hyphenated(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut capitalize_next = true;
+    for c in s.chars() {
+        if capitalize_next {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+        capitalize_next = c.is_whitespace() || c == '-' || c == '\'';
+    }
+    result
+}
+
+/// Converts Windows (`\r\n`) and old Mac (`\r`) line endings to Unix (`\n`)
+/// 
+/// # Arguments
+/// * `s` - The string to normalize
+/// 
+/// # Returns
+/// A new string using only `\n` line endings
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::normalize_newlines;
+/// assert_eq!(normalize_newlines("a\r\nb\rc\n"), "a\nb\nc\n");
+/// ```
+pub fn normalize_newlines(s: &str) -> String {
+    s.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Counts the user-perceived characters (grapheme clusters) in a string
+/// 
+/// Unlike counting `char`s, an emoji built from several code points or a
+/// letter followed by a combining accent counts as one character.
+/// 
+/// # Arguments
+/// * `s` - The string to measure
+/// 
+/// # Returns
+/// The number of grapheme clusters
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::grapheme_count;
+/// assert_eq!(grapheme_count("e\u{301}"), 1);
+/// assert_eq!(grapheme_count("hello"), 5);
+/// ```
+pub fn grapheme_count(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Reverses a string without breaking apart grapheme clusters
+/// 
+/// # Arguments
+/// * `s` - The string to reverse
+/// 
+/// # Returns
+/// A new string with grapheme clusters in reverse order
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::reverse_graphemes;
+/// assert_eq!(reverse_graphemes("ae\u{301}"), "e\u{301}a");
+/// ```
+pub fn reverse_graphemes(s: &str) -> String {
+    s.graphemes(true).rev().collect()
+}
+
+/// Renders labelled values as a horizontal ASCII bar chart
+/// 
+/// Each line holds the label (left-aligned to the longest label), a bar of
+/// `█` characters and the value. The largest value gets a bar of `max_width`
+/// characters; the others are scaled proportionally and rounded to the
+/// nearest character. Lines are separated by `\n`.
+/// 
+/// # Arguments
+/// * `values` - The labels and values to chart, in display order
+/// * `max_width` - The length of the longest bar in characters
+/// 
+/// # Returns
+/// The rendered chart, or an empty string if `values` is empty
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::bar_chart;
+/// let chart = bar_chart(&[("apples".to_string(), 4), ("figs".to_string(), 2)], 8);
+/// assert_eq!(chart, "apples ████████ 4\nfigs   ████ 2");
+/// ```
+pub fn bar_chart(values: &[(String, u64)], max_width: usize) -> String {
+    let label_width = values.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0);
+    let max_value = values.iter().map(|&(_, value)| value).max().unwrap_or(0);
+    values
+        .iter()
+        .map(|(label, value)| {
+            let bar_len = if max_value == 0 {
+                0
+            } else {
+                ((*value as f64 / max_value as f64) * max_width as f64).round() as usize
+            };
+            format!("{:<width$} {} {}", label, "█".repeat(bar_len), value, width = label_width)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps text into lines of at most `width` characters, splitting over-long words
+/// 
+/// Behaves like `wrap_text`, except that a word longer than `width` (such as
+/// a long URL) is broken across as many lines as needed instead of
+/// overflowing. A `width` of 0 is treated as 1.
+/// 
+/// # Arguments
+/// * `s` - The text to wrap
+/// * `width` - The maximum line width in characters
+/// 
+/// # Returns
+/// The wrapped lines, none of which is longer than `width`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::hard_wrap;
+/// assert_eq!(hard_wrap("see abcdefghij", 4), vec!["see", "abcd", "efgh", "ij"]);
+/// ```
+pub fn hard_wrap(s: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    for word in s.split_whitespace() {
+        let mut chars: Vec<char> = word.chars().collect();
+        if current_len > 0 && current_len + 1 + chars.len() > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        while chars.len() > width {
+            let rest = chars.split_off(width);
+            lines.push(chars.into_iter().collect());
+            chars = rest;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.extend(&chars);
+        current_len += chars.len();
+    }
+    if current_len > 0 {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Checks whether a string looks like an email address
+/// 
+/// This is a pragmatic format check, not a full RFC 5322 validator: the
+/// string must contain no whitespace and exactly one `@`, with a non-empty
+/// local part before it and a domain after it made of at least two non-empty
+/// dot-separated labels.
+/// 
+/// # Arguments
+/// * `s` - The string to check
+/// 
+/// # Returns
+/// true if the string looks like an email address, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::is_valid_email;
+/// assert!(is_valid_email("jane.doe@example.com"));
+/// assert!(!is_valid_email("foo@"));
+/// assert!(!is_valid_email("no-at-sign"));
+/// ```
+pub fn is_valid_email(s: &str) -> bool {
+    if s.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let Some((local, domain)) = s.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+/// Parses a dotted-quad IPv4 address such as "192.168.1.1"
+/// 
+/// The address must have exactly four decimal octets in the range 0-255,
+/// separated by dots. Octets may not have leading zeros (other than "0"
+/// itself), signs or surrounding whitespace.
+/// 
+/// # Arguments
+/// * `s` - The string to parse
+/// 
+/// # Returns
+/// The four octets of the address, or `None` if the string is malformed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::parse_ipv4;
+/// assert_eq!(parse_ipv4("192.168.1.1"), Some([192, 168, 1, 1]));
+/// assert_eq!(parse_ipv4("256.1.1.1"), None);
+/// assert_eq!(parse_ipv4("1.2.3"), None);
+/// ```
+pub fn parse_ipv4(s: &str) -> Option<[u8; 4]> {
+    let mut octets = [0u8; 4];
+    let mut parts = s.split('.');
+    for octet in octets.iter_mut() {
+        let part = parts.next()?;
+        let valid = !part.is_empty()
+            && part.len() <= 3
+            && part.bytes().all(|b| b.is_ascii_digit())
+            && (part == "0" || !part.starts_with('0'));
+        if !valid {
+            return None;
+        }
+        *octet = part.parse().ok()?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(octets)
+}
+
+/// Checks whether a string is a valid dotted-quad IPv4 address
+/// 
+/// See `parse_ipv4` for the accepted format.
+/// 
+/// # Arguments
+/// * `s` - The string to check
+/// 
+/// # Returns
+/// true if the string is a valid IPv4 address, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::is_valid_ipv4;
+/// assert!(is_valid_ipv4("10.0.0.255"));
+/// assert!(!is_valid_ipv4("10.0.0.01"));
+/// ```
+pub fn is_valid_ipv4(s: &str) -> bool {
+    parse_ipv4(s).is_some()
+}
+
+/// Converts a string to title case, fully uppercasing known acronyms
+/// 
+/// Words are title-cased as by `to_title_case`, except that a word matching
+/// one of `acronyms` (case-insensitively, ignoring surrounding punctuation
+/// such as a trailing comma) is uppercased entirely.
+/// 
+/// # Arguments
+/// * `s` - The string to convert
+/// * `acronyms` - The words to uppercase fully
+/// 
+/// # Returns
+/// A new string in title case
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::to_title_case_with_acronyms;
+/// assert_eq!(to_title_case_with_acronyms("the nasa faq", &["nasa", "faq"]), "The NASA FAQ");
+/// ```
+pub fn to_title_case_with_acronyms(s: &str, acronyms: &[&str]) -> String {
+    s.split_whitespace()
+        .map(|word| {
+            let core = word.trim_matches(|c: char| c.is_ascii_punctuation());
+            if acronyms.iter().any(|acronym| acronym.to_lowercase() == core.to_lowercase()) {
+                word.to_uppercase()
+            } else {
+                to_title_case(word)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits a string into whitespace-separated tokens, optionally stripping punctuation
+/// 
+/// With `keep_punctuation` set, this is the same as `split_whitespace`. Without
+/// it, non-alphanumeric characters are trimmed from both ends of each token,
+/// so "Hello," becomes "Hello" while inner punctuation such as the apostrophe
+/// in "don't" is kept; tokens made only of punctuation (like "-") are dropped.
+/// 
+/// # Arguments
+/// * `s` - The string to tokenize
+/// * `keep_punctuation` - Whether to leave surrounding punctuation on tokens
+/// 
+/// # Returns
+/// The tokens in order
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::tokenize;
+/// assert_eq!(tokenize("Hello, world!", false), vec!["Hello", "world"]);
+/// assert_eq!(tokenize("Hello, world!", true), vec!["Hello,", "world!"]);
+/// ```
+pub fn tokenize(s: &str, keep_punctuation: bool) -> Vec<String> {
+    s.split_whitespace()
+        .map(|token| {
+            if keep_punctuation {
+                token
+            } else {
+                token.trim_matches(|c: char| !c.is_alphanumeric())
+            }
+        })
+        .filter(|token| !token.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Returns the singular or a naive English plural of a noun, depending on a count
+/// 
+/// The word is returned unchanged when `count` is 1. Otherwise "es" is added
+/// after s, x, ch and sh, a consonant followed by y becomes "ies", and "s" is
+/// added to everything else. Irregular nouns ("child", "mouse") are not
+/// handled.
+/// 
+/// # Arguments
+/// * `word` - The singular noun
+/// * `count` - The number of items
+/// 
+/// # Returns
+/// The word in the form matching `count`
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::pluralize;
+/// assert_eq!(pluralize("file", 3), "files");
+/// assert_eq!(pluralize("file", 1), "file");
+/// assert_eq!(pluralize("berry", 0), "berries");
+/// ```
+pub fn pluralize(word: &str, count: u64) -> String {
+    if count == 1 {
+        return word.to_string();
+    }
+    let lower = word.to_lowercase();
+    if ["s", "x", "ch", "sh"].iter().any(|suffix| lower.ends_with(suffix)) {
+        return format!("{}es", word);
+    }
+    let mut chars = lower.chars().rev();
+    if let (Some('y'), Some(before)) = (chars.next(), chars.next()) {
+        if before.is_alphabetic() && !"aeiou".contains(before) {
+            return format!("{}ies", &word[..word.len() - 1]);
+        }
+    }
+    format!("{}s", word)
+}
+
+/// Parses a hex color such as "#ff8800" or "#f80" into its RGB components
+/// 
+/// Accepts six-digit `RRGGBB` and three-digit `RGB` shorthand (where each
+/// digit is doubled, so "f00" means "ff0000"), with or without a leading `#`.
+/// Hex digits are case-insensitive.
+/// 
+/// # Arguments
+/// * `s` - The color string to parse
+/// 
+/// # Returns
+/// The `(red, green, blue)` components, or `None` if the string is not a
+/// valid hex color
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::parse_hex_color;
+/// assert_eq!(parse_hex_color("#FF8800"), Some((255, 136, 0)));
+/// assert_eq!(parse_hex_color("f00"), Some((255, 0, 0)));
+/// assert_eq!(parse_hex_color("#12345"), None);
+/// ```
+pub fn parse_hex_color(s: &str) -> Option<(u8, u8, u8)> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let component = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    match hex.len() {
+        6 => Some((component(&hex[0..2])?, component(&hex[2..4])?, component(&hex[4..6])?)),
+        3 => {
+            let short = |i: usize| component(&hex[i..=i]).map(|d| d * 17);
+            Some((short(0)?, short(1)?, short(2)?))
+        }
+        _ => None,
+    }
+}
+
+/// Checks whether a string is a valid hex color
+/// 
+/// See `parse_hex_color` for the accepted formats.
+/// 
+/// # Arguments
+/// * `s` - The string to check
+/// 
+/// # Returns
+/// true if the string is a valid hex color, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::is_valid_hex_color;
+/// assert!(is_valid_hex_color("#1e90ff"));
+/// assert!(!is_valid_hex_color("#1e90fg"));
+/// ```
+pub fn is_valid_hex_color(s: &str) -> bool {
+    parse_hex_color(s).is_some()
+}
+
+/// Removes a prefix repeatedly until the string no longer starts with it
+/// 
+/// # Arguments
+/// * `s` - The string to trim
+/// * `prefix` - The prefix to remove; an empty prefix leaves `s` unchanged
+/// 
+/// # Returns
+/// A new string with every leading occurrence of `prefix` removed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::trim_prefix_all;
+/// assert_eq!(trim_prefix_all("xxxxdata", "xx"), "data");
+/// assert_eq!(trim_prefix_all("xxxdata", "xx"), "xdata");
+/// ```
+pub fn trim_prefix_all(s: &str, prefix: &str) -> String {
+    let mut rest = s;
+    if !prefix.is_empty() {
+        while let Some(stripped) = rest.strip_prefix(prefix) {
+            rest = stripped;
+        }
+    }
+    rest.to_string()
+}
+
+/// Removes a suffix repeatedly until the string no longer ends with it
+/// 
+/// # Arguments
+/// * `s` - The string to trim
+/// * `suffix` - The suffix to remove; an empty suffix leaves `s` unchanged
+/// 
+/// # Returns
+/// A new string with every trailing occurrence of `suffix` removed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::trim_suffix_all;
+/// assert_eq!(trim_suffix_all("data.bak.bak", ".bak"), "data");
+/// ```
+pub fn trim_suffix_all(s: &str, suffix: &str) -> String {
+    let mut rest = s;
+    if !suffix.is_empty() {
+        while let Some(stripped) = rest.strip_suffix(suffix) {
+            rest = stripped;
+        }
+    }
+    rest.to_string()
+}
+
+/// Calculates the Levenshtein edit distance between two strings
+/// 
+/// This is the minimum number of single-character insertions, deletions and
+/// substitutions needed to turn `a` into `b`, counted over chars.
+/// 
+/// # Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+/// 
+/// # Returns
+/// The edit distance
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::levenshtein;
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("ca", "ac"), 2);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // previous[j] is the distance between the chars of `a` seen so far and b[..j]
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Calculates the Damerau-Levenshtein edit distance between two strings
+/// 
+/// Like `levenshtein`, but swapping two adjacent characters also counts as a
+/// single edit, so "ca" to "ac" is 1 rather than 2. This is the optimal
+/// string alignment variant: a substring is not edited again after being
+/// transposed.
+/// 
+/// # Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+/// 
+/// # Returns
+/// The edit distance
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::damerau_levenshtein;
+/// assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+/// assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // d[i][j] is the distance between a[..i] and b[..j]
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// Checks whether a word can be spelled from a pool of letters
+/// 
+/// Each letter in `letters` can be used at most as many times as it appears,
+/// and not every letter has to be used. Comparison ignores case.
+/// 
+/// # Arguments
+/// * `word` - The word to spell
+/// * `letters` - The available letters
+/// 
+/// # Returns
+/// true if `word` can be formed from `letters`, false otherwise
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::can_form;
+/// assert!(can_form("cat", "atcx"));
+/// assert!(!can_form("cat", "ca"));
+/// ```
+pub fn can_form(word: &str, letters: &str) -> bool {
+    let mut available: HashMap<char, usize> = HashMap::new();
+    for c in letters.chars().flat_map(char::to_lowercase) {
+        *available.entry(c).or_insert(0) += 1;
+    }
+    word.chars().flat_map(char::to_lowercase).all(|c| match available.get_mut(&c) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Collapses runs of whitespace to single spaces and trims both ends
+/// 
+/// Tabs, newlines and other Unicode whitespace all count as whitespace.
+/// 
+/// # Arguments
+/// * `s` - The string to normalize
+/// 
+/// # Returns
+/// A new string with single spaces between words
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::normalize_whitespace;
+/// assert_eq!(normalize_whitespace("  hello   world  "), "hello world");
+/// assert_eq!(normalize_whitespace("a\t\tb\nc"), "a b c");
+/// ```
+pub fn normalize_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Counts the words in a string using Unicode word boundaries (UAX #29)
+/// 
+/// Unlike `word_count`, punctuation-only tokens such as "--" are not counted,
+/// hyphenated words count as their parts, apostrophes inside words don't
+/// split them, and text in scripts written without spaces is still broken
+/// into words (each Han ideograph counts as one word).
+/// 
+/// # Arguments
+/// * `s` - The string to count words in
+/// 
+/// # Returns
+/// The number of words
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::unicode_word_count;
+/// assert_eq!(unicode_word_count("can't stop -- won't stop"), 4);
+/// assert_eq!(unicode_word_count("你好世界"), 4);
+/// ```
+pub fn unicode_word_count(s: &str) -> usize {
+    s.unicode_words().count()
+}
+
+/// Counts the occurrences of a character in a string, ignoring case
+/// 
+/// Characters match if they have the same Unicode lowercase or uppercase
+/// mapping, so this handles non-ASCII letters ('É' and 'é') and letters
+/// with several lowercase forms (Greek 'Σ', 'σ' and final 'ς').
+/// 
+/// # Arguments
+/// * `s` - The string to search in
+/// * `target` - The character to count, in either case
+/// 
+/// # Returns
+/// The number of occurrences of the target character in any case
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::count_char_ignore_case;
+/// assert_eq!(count_char_ignore_case("LLlama", 'l'), 3);
+/// assert_eq!(count_char_ignore_case("Été", 'é'), 2);
+/// ```
+pub fn count_char_ignore_case(s: &str, target: char) -> usize {
+    s.chars()
+        .filter(|&c| c.to_lowercase().eq(target.to_lowercase()) || c.to_uppercase().eq(target.to_uppercase()))
+        .count()
+}
+
+/// Calculates a normalized similarity score between two strings
+/// 
+/// The score is `1 - levenshtein(a, b) / max_len`, where `max_len` is the
+/// length in chars of the longer string, so it is easier to compare against
+/// a fixed threshold than a raw edit distance.
+/// 
+/// # Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+/// 
+/// # Returns
+/// A score from 0.0 (nothing in common) to 1.0 (identical). Two empty
+/// strings score 1.0.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::similarity_ratio;
+/// assert_eq!(similarity_ratio("rust", "rust"), 1.0);
+/// assert_eq!(similarity_ratio("rust", "bust"), 0.75);
+/// ```
+pub fn similarity_ratio(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+/// Maps a precomposed accented Latin letter to its unaccented base letter
+fn base_letter(c: char) -> Option<char> {
+    let base = match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' => 'A',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => 'C',
+        'ď' | 'đ' => 'd',
+        'Ď' | 'Đ' => 'D',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'È'..='Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => 'E',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => 'G',
+        'ĥ' | 'ħ' => 'h',
+        'Ĥ' | 'Ħ' => 'H',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'Ì'..='Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => 'I',
+        'ĵ' => 'j',
+        'Ĵ' => 'J',
+        'ķ' => 'k',
+        'Ķ' => 'K',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => 'L',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => 'N',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => 'O',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'Ŕ' | 'Ŗ' | 'Ř' => 'R',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => 'S',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'Ţ' | 'Ť' | 'Ŧ' => 'T',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'Ù'..='Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => 'U',
+        'ŵ' => 'w',
+        'Ŵ' => 'W',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'Ý' | 'Ÿ' | 'Ŷ' => 'Y',
+        'ź' | 'ż' | 'ž' => 'z',
+        'Ź' | 'Ż' | 'Ž' => 'Z',
+        _ => return None,
+    };
+    Some(base)
+}
+
+/// Removes accents from Latin letters
+/// 
+/// Precomposed letters such as 'é' are replaced by their base letter, and
+/// combining accents (U+0300 to U+036F) are dropped. All other characters
+/// are kept as they are.
+/// 
+/// # Arguments
+/// * `s` - The string to strip
+/// 
+/// # Returns
+/// The string with accents removed
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::strip_accents;
+/// assert_eq!(strip_accents("Crème brûlée"), "Creme brulee");
+/// assert_eq!(strip_accents("cafe\u{301}"), "cafe");
+/// ```
+pub fn strip_accents(s: &str) -> String {
+    s.chars()
+        .filter(|c| !('\u{300}'..='\u{36F}').contains(c))
+        .map(|c| base_letter(c).unwrap_or(c))
+        .collect()
+}
+
+/// Checks if a string contains only ASCII characters
+/// 
+/// # Arguments
+/// * `s` - The string to check
+/// 
+/// # Returns
+/// `true` if every character is ASCII, including for an empty string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::is_ascii;
+/// assert!(is_ascii("hello"));
+/// assert!(!is_ascii("héllo"));
+/// ```
+pub fn is_ascii(s: &str) -> bool {
+    s.is_ascii()
+}
+
+/// Converts a string to ASCII for systems that can't handle anything else
+/// 
+/// Accents are stripped with `strip_accents`, then any character that is
+/// still not ASCII (emoji, CJK, symbols) is dropped.
+/// 
+/// # Arguments
+/// * `s` - The string to convert
+/// 
+/// # Returns
+/// An ASCII-only version of the string
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::to_ascii_safe;
+/// assert_eq!(to_ascii_safe("Mötley Crüe"), "Motley Crue");
+/// assert_eq!(to_ascii_safe("ok 👍"), "ok ");
+/// ```
+pub fn to_ascii_safe(s: &str) -> String {
+    strip_accents(s).chars().filter(char::is_ascii).collect()
+}
+
+/// Superscript digits, indexed by the digit they stand for
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Subscript digits, indexed by the digit they stand for
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// Writes a number's decimal digits using the given digit characters
+fn map_digits(n: u64, digits: &[char; 10]) -> String {
+    n.to_string()
+        .bytes()
+        .map(|b| digits[(b - b'0') as usize])
+        .collect()
+}
+
+/// Converts a number to Unicode superscript digits
+/// 
+/// # Arguments
+/// * `n` - The number to convert
+/// 
+/// # Returns
+/// The number written in superscript, for exponents in plain text
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::to_superscript;
+/// assert_eq!(format!("x{}", to_superscript(23)), "x²³");
+/// ```
+pub fn to_superscript(n: u64) -> String {
+    map_digits(n, &SUPERSCRIPT_DIGITS)
+}
+
+/// Converts a number to Unicode subscript digits
+/// 
+/// # Arguments
+/// * `n` - The number to convert
+/// 
+/// # Returns
+/// The number written in subscript, for indices in plain text
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::to_subscript;
+/// assert_eq!(format!("H{}O", to_subscript(2)), "H₂O");
+/// ```
+pub fn to_subscript(n: u64) -> String {
+    map_digits(n, &SUBSCRIPT_DIGITS)
+}
+
+/// Shifts each ASCII letter in a string by `shift` places, wrapping around
+/// 
+/// This is the Caesar cipher: encode with a positive shift and decode with
+/// the same shift negated. Case is preserved and other characters are left
+/// unchanged.
+/// 
+/// # Arguments
+/// * `s` - The text to shift
+/// * `shift` - How many places to shift each letter, may be negative
+/// 
+/// # Returns
+/// The shifted text
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::caesar_shift;
+/// assert_eq!(caesar_shift("Hello, World!", 3), "Khoor, Zruog!");
+/// assert_eq!(caesar_shift("Khoor, Zruog!", -3), "Hello, World!");
+/// ```
+pub fn caesar_shift(s: &str, shift: i32) -> String {
+    let shift = shift.rem_euclid(26) as u8;
+    s.chars()
+        .map(|c| {
+            let base = match c {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return c,
+            };
+            ((c as u8 - base + shift) % 26 + base) as char
+        })
+        .collect()
+}
+
+/// Relative frequency of each letter a-z in English text, in percent
+const ENGLISH_LETTER_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+
+/// Scores how far a text's letter counts are from English with chi-squared
+fn english_chi_squared(s: &str) -> f64 {
+    let mut counts = [0usize; 26];
+    for c in s.chars().filter(char::is_ascii_alphabetic) {
+        counts[(c.to_ascii_lowercase() as u8 - b'a') as usize] += 1;
+    }
+    let total: usize = counts.iter().sum();
+    counts
+        .iter()
+        .zip(ENGLISH_LETTER_FREQUENCIES)
+        .map(|(&observed, frequency)| {
+            let expected = total as f64 * frequency / 100.0;
+            (observed as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+/// Breaks a Caesar cipher by trying all 26 shifts
+/// 
+/// Each candidate decoding is scored by comparing its letter frequencies to
+/// those of English with a chi-squared test, and the closest one wins. This
+/// works well for a sentence or more of English, but short texts may not
+/// have enough letters for a reliable guess.
+/// 
+/// # Arguments
+/// * `ciphertext` - Text encoded with `caesar_shift`
+/// 
+/// # Returns
+/// A tuple of the shift that was used to encode the text (0 to 25) and the
+/// decoded text. Text without letters returns a shift of 0 and is unchanged.
+/// 
+/// # Examples
+/// ```
+/// use cli_utils::string_utils::{caesar_crack, caesar_shift};
+/// let secret = caesar_shift("Meet me at the old bridge at noon", 7);
+/// assert_eq!(caesar_crack(&secret), (7, "Meet me at the old bridge at noon".to_string()));
+/// ```
+pub fn caesar_crack(ciphertext: &str) -> (i32, String) {
+    if !ciphertext.chars().any(|c| c.is_ascii_alphabetic()) {
+        return (0, ciphertext.to_string());
+    }
+    (0..26)
+        .map(|shift| (shift, caesar_shift(ciphertext, -shift)))
+        .min_by(|(_, a), (_, b)| english_chi_squared(a).total_cmp(&english_chi_squared(b)))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(is_palindrome("racecar"));
+        assert!(!is_palindrome("hello"));
+        assert!(is_palindrome("A man a plan a canal Panama"));
+        assert!(!is_palindrome("race a car"));
+        assert!(is_palindrome(""));
+        assert!(is_palindrome("a"));
+    }
+
+    #[test]
+    fn test_count_char() {
+        assert_eq!(count_char("hello world", 'l'), 3);
+        assert_eq!(count_char("rust programming", 'r'), 3);
+        assert_eq!(count_char("hello", 'x'), 0);
+        assert_eq!(count_char("", 'a'), 0);
+    }
+
+    #[test]
+    fn test_reverse_string() {
+        assert_eq!(reverse_string("hello"), "olleh");
+        assert_eq!(reverse_string("rust"), "tsur");
+        assert_eq!(reverse_string(""), "");
+        assert_eq!(reverse_string("a"), "a");
+    }
+
+    #[test]
+    fn test_to_title_case() {
+        assert_eq!(to_title_case("hello world"), "Hello World");
+        assert_eq!(to_title_case("rust programming"), "Rust Programming");
+        assert_eq!(to_title_case("HELLO WORLD"), "Hello World");
+        assert_eq!(to_title_case(""), "");
+    }
+
+    #[test]
+    fn test_remove_whitespace() {
+        assert_eq!(remove_whitespace("hello world"), "helloworld");
+        assert_eq!(remove_whitespace("  rust  programming  "), "rustprogramming");
+        assert_eq!(remove_whitespace(""), "");
+        assert_eq!(remove_whitespace("   "), "");
+    }
+
+    #[test]
+    fn test_word_count() {
+        assert_eq!(word_count("hello world"), 2);
+        assert_eq!(word_count("  rust  programming  language  "), 3);
+        assert_eq!(word_count(""), 0);
+        assert_eq!(word_count("   "), 0);
+        assert_eq!(word_count("single"), 1);
+    }
+
+    #[test]
+    fn test_is_alphabetic() {
+        assert!(is_alphabetic("hello"));
+        assert!(!is_alphabetic("hello123"));
+        assert!(!is_alphabetic(""));
+        assert!(!is_alphabetic("hello world")); // contains space
+        assert!(is_alphabetic("HelloWorld"));
+    }
+
+    #[test]
+    fn test_indent() {
+        let block = "fn main() {\n    run();\n}";
+        assert_eq!(indent(block, 4), "    fn main() {\n        run();\n    }");
+        assert_eq!(indent("a\n\nb", 2), "  a\n\n  b");
+        assert_eq!(indent("", 4), "");
+    }
+
+    #[test]
+    fn test_dedent() {
+        let block = "    fn main() {\n        run();\n    }";
+        assert_eq!(dedent(block), "fn main() {\n    run();\n}");
+        assert_eq!(dedent(&indent("a\n  b\nc", 3)), "a\n  b\nc");
+        assert_eq!(dedent("  a\n   \n  b"), "a\n\nb");
+        assert_eq!(dedent("no indent"), "no indent");
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal(0), "0th");
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(4), "4th");
+    }
+
+    #[test]
+    fn test_ordinal_teens() {
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(13), "13th");
+        assert_eq!(ordinal(111), "111th");
+        assert_eq!(ordinal(113), "113th");
+    }
+
+    #[test]
+    fn test_ordinal_twenties() {
+        assert_eq!(ordinal(21), "21st");
+        assert_eq!(ordinal(22), "22nd");
+        assert_eq!(ordinal(23), "23rd");
+        assert_eq!(ordinal(101), "101st");
+    }
+
+    #[test]
+    fn test_word_frequency() {
+        let freq = word_frequency("The cat and THE hat and the bat");
+        assert_eq!(freq["the"], 3);
+        assert_eq!(freq["and"], 2);
+        assert_eq!(freq["cat"], 1);
+        assert_eq!(freq.len(), 5);
+        assert!(word_frequency("").is_empty());
+    }
+
+    #[test]
+    fn test_most_common_words() {
+        let top = most_common_words("b a b c a b d", 3);
+        assert_eq!(top, vec![("b".to_string(), 3), ("a".to_string(), 2), ("c".to_string(), 1)]);
+        assert_eq!(most_common_words("one", 5), vec![("one".to_string(), 1)]);
+        assert!(most_common_words("", 5).is_empty());
+    }
+
+    #[test]
+    fn test_distinct_word_count() {
+        assert_eq!(distinct_word_count("the cat the dog"), 3);
+        assert_eq!(distinct_word_count("one two three four"), 4);
+        assert_eq!(distinct_word_count("echo echo echo"), 1);
+        assert_eq!(distinct_word_count("Rust rust RUST Go"), 2);
+        assert_eq!(distinct_word_count(""), 0);
+    }
+
+    #[test]
+    fn test_is_subsequence() {
+        assert!(is_subsequence("ace", "abcde"));
+        assert!(is_subsequence("abcde", "abcde"));
+        assert!(is_subsequence("", "abcde"));
+        assert!(is_subsequence("", ""));
+        assert!(!is_subsequence("a", ""));
+        assert!(!is_subsequence("abcdef", "abcde"));
+    }
+
+    #[test]
+    fn test_is_subsequence_out_of_order() {
+        // every char is present, but 'e' comes before 'c' in the needle
+        assert!(!is_subsequence("aec", "abcde"));
+        assert!(!is_subsequence("ba", "ab"));
+        assert!(!is_subsequence("aa", "ab"));
+    }
+
+    #[test]
+    fn test_humanize_filename() {
+        assert_eq!(humanize_filename("my_report_final.txt"), "My Report Final");
+        assert_eq!(humanize_filename("release-notes.md"), "Release Notes");
+        assert_eq!(humanize_filename("mixed_up-name.csv"), "Mixed Up Name");
+        assert_eq!(humanize_filename("archive.tar.gz"), "Archive.tar");
+        assert_eq!(humanize_filename("README"), "Readme");
+        assert_eq!(humanize_filename(".hidden_file"), ".hidden File");
+        assert_eq!(humanize_filename("double__underscore.rs"), "Double Underscore");
+    }
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(mask("1234567812343456", 4, 4, '*'), "1234********3456");
+        assert_eq!(mask("secret", 0, 0, '#'), "######");
+        assert_eq!(mask("password", 1, 0, 'x'), "pxxxxxxx");
+        assert_eq!(mask("ünïcödé", 1, 1, '*'), "ü*****é");
+    }
+
+    #[test]
+    fn test_mask_visible_exceeds_length() {
+        assert_eq!(mask("abc", 2, 2, '*'), "abc");
+        assert_eq!(mask("abcd", 2, 2, '*'), "abcd");
+        assert_eq!(mask("abc", 10, 0, '*'), "abc");
+        assert_eq!(mask("", 0, 0, '*'), "");
+        assert_eq!(mask("abc", usize::MAX, usize::MAX, '*'), "abc");
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("the quick brown fox jumps", 10), vec!["the quick", "brown fox", "jumps"]);
+        assert_eq!(wrap_text("  spaced   out  ", 20), vec!["spaced out"]);
+        assert_eq!(wrap_text("a supercalifragilistic word", 10), vec!["a", "supercalifragilistic", "word"]);
+        assert_eq!(wrap_text("exactly ten", 11), vec!["exactly ten"]);
+        assert!(wrap_text("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_reflow_preserves_paragraphs() {
+        let text = "The quick brown fox\njumps over the lazy dog.\n\nA second paragraph that is\nlong enough to wrap.\n\n\nThird.";
+        let expected = "The quick brown\nfox jumps over\nthe lazy dog.\n\nA second\nparagraph that\nis long enough\nto wrap.\n\nThird.";
+        assert_eq!(reflow(text, 15), expected);
+    }
+
+    #[test]
+    fn test_reflow_rewraps_within_paragraph() {
+        assert_eq!(reflow("one\ntwo\nthree", 20), "one two three");
+        assert_eq!(reflow("\n\nleading and trailing\n\n", 7), "leading\nand\ntrailing");
+        assert_eq!(reflow("", 10), "");
+    }
+
+    #[test]
+    fn test_char_frequency() {
+        let freq = char_frequency("hello world");
+        assert_eq!(freq[&'l'], 3);
+        assert_eq!(freq[&'o'], 2);
+        assert_eq!(freq[&' '], 1);
+        assert_eq!(freq.len(), 8);
+        assert!(char_frequency("").is_empty());
+    }
+
+    #[test]
+    fn test_frequency_sorted() {
+        assert_eq!(frequency_sorted("mississippi"), vec![('i', 4), ('s', 4), ('p', 2), ('m', 1)]);
+        assert_eq!(frequency_sorted("cba"), vec![('a', 1), ('b', 1), ('c', 1)]);
+        assert!(frequency_sorted("").is_empty());
+    }
+
+    #[test]
+    fn test_rle_encode() {
+        assert_eq!(rle_encode("aaabbc"), "a3b2c1");
+        assert_eq!(rle_encode("abc"), "a1b1c1");
+        assert_eq!(rle_encode("zzzzzzzzzzzz"), "z12");
+        assert_eq!(rle_encode(""), "");
+    }
+
+    #[test]
+    fn test_rle_decode() {
+        assert_eq!(rle_decode("a3b2c1").unwrap(), "aaabbc");
+        assert_eq!(rle_decode("z12").unwrap(), "zzzzzzzzzzzz");
+        assert_eq!(rle_decode("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_rle_decode_malformed() {
+        assert_eq!(rle_decode("a3b").unwrap_err(), "Missing count after 'b'");
+        assert_eq!(rle_decode("3a").unwrap_err(), "Expected a character but found digit '3'");
+        assert_eq!(rle_decode("a0").unwrap_err(), "Count after 'a' must be greater than zero");
+        assert!(rle_decode("a99999999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_rle_decode_too_long() {
+        let error = "Decoded output would exceed the maximum of 10000000 characters";
+        assert_eq!(rle_decode("a99999999999999").unwrap_err(), error);
+        assert_eq!(rle_decode("a6000000b6000000").unwrap_err(), error);
+        assert_eq!(rle_decode("a10000000").unwrap().len(), 10_000_000);
+    }
+
+    #[test]
+    fn test_rle_round_trip() {
+        for s in ["aaabbc", "hello world", "Mississippi", "abcabc", "ééé  üü", "x"] {
+            assert_eq!(rle_decode(&rle_encode(s)).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(group_thousands(1234567, ','), "1,234,567");
+        assert_eq!(group_thousands(1000, ','), "1,000");
+        assert_eq!(group_thousands(100000, ','), "100,000");
+        assert_eq!(group_thousands(i64::MAX, ','), "9,223,372,036,854,775,807");
+    }
+
+    #[test]
+    fn test_group_thousands_small_numbers() {
+        assert_eq!(group_thousands(0, ','), "0");
+        assert_eq!(group_thousands(7, ','), "7");
+        assert_eq!(group_thousands(999, ','), "999");
+        assert_eq!(group_thousands(-999, ','), "-999");
+    }
+
+    #[test]
+    fn test_group_thousands_negative_and_custom_separator() {
+        assert_eq!(group_thousands(-1234567, ','), "-1,234,567");
+        assert_eq!(group_thousands(i64::MIN, ','), "-9,223,372,036,854,775,808");
+        assert_eq!(group_thousands(1234567, ' '), "1 234 567");
+        assert_eq!(group_thousands(-1234567, '.'), "-1.234.567");
+    }
+
+    #[test]
+    fn test_crc32_bytes() {
+        assert_eq!(crc32_bytes(b"123456789"), 0xCBF43926);
+        assert_eq!(crc32_bytes(b""), 0);
+        assert_eq!(crc32_bytes(b"The quick brown fox jumps over the lazy dog"), 0x414FA339);
+    }
+
+    #[test]
+    fn test_crc32_update_in_pieces() {
+        let partial = crc32_update(0, b"12345");
+        assert_eq!(crc32_update(partial, b"6789"), crc32_bytes(b"123456789"));
+    }
+
+    #[test]
+    fn test_to_title_case_hyphenated() {
+        assert_eq!(to_title_case_hyphenated("o'brien mother-in-law"), "O'Brien Mother-In-Law");
+        assert_eq!(to_title_case_hyphenated("O'NEILL"), "O'Neill");
+        assert_eq!(to_title_case_hyphenated("jean-luc picard"), "Jean-Luc Picard");
+        assert_eq!(to_title_case_hyphenated("hello world"), "Hello World");
+        assert_eq!(to_title_case_hyphenated("RUST programming"), "Rust Programming");
+        assert_eq!(to_title_case_hyphenated(""), "");
+    }
+
+    #[test]
+    fn test_normalize_newlines() {
+        assert_eq!(normalize_newlines("a\r\nb\rc\nd"), "a\nb\nc\nd");
+        assert_eq!(normalize_newlines("\r\n\r\n"), "\n\n");
+        assert_eq!(normalize_newlines("\r\r\n"), "\n\n");
+        assert_eq!(normalize_newlines("no newlines"), "no newlines");
+        assert_eq!(normalize_newlines(""), "");
+    }
+
+    #[test]
+    fn test_grapheme_count() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(family.chars().count(), 5);
+        assert_eq!(grapheme_count(family), 1);
+
+        let accented = "cafe\u{301}";
+        assert_eq!(accented.chars().count(), 5);
+        assert_eq!(grapheme_count(accented), 4);
+
+        assert_eq!(grapheme_count("hello"), 5);
+        assert_eq!(grapheme_count(""), 0);
+    }
+
+    #[test]
+    fn test_reverse_graphemes() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(reverse_graphemes(&format!("a{}b", family)), format!("b{}a", family));
+        assert_eq!(reverse_graphemes("cafe\u{301}"), "e\u{301}fac");
+        assert_ne!(reverse_string("cafe\u{301}"), "e\u{301}fac");
+        assert_eq!(reverse_graphemes("hello"), reverse_string("hello"));
+    }
+
+    #[test]
+    fn test_bar_chart() {
+        let values = vec![
+            ("the".to_string(), 10),
+            ("fox".to_string(), 5),
+            ("a".to_string(), 2),
+            ("zero".to_string(), 0),
+        ];
+        let chart = bar_chart(&values, 20);
+        let bar_lengths: Vec<usize> = chart
+            .lines()
+            .map(|line| line.chars().filter(|&c| c == '█').count())
+            .collect();
+        assert_eq!(bar_lengths, vec![20, 10, 4, 0]);
+        assert!(chart.lines().all(|line| line.chars().nth(4) == Some(' ')));
+        assert_eq!(chart.lines().nth(2), Some("a    ████ 2"));
+
+        assert_eq!(bar_chart(&[], 10), "");
+        assert_eq!(bar_chart(&[("x".to_string(), 0)], 10), "x  0");
+    }
+
+    #[test]
+    fn test_hard_wrap() {
+        assert_eq!(
+            hard_wrap("go to https://example.com/a/very/long/path now", 12),
+            vec!["go to", "https://exam", "ple.com/a/ve", "ry/long/path", "now"]
+        );
+        assert_eq!(hard_wrap("the quick brown fox", 10), wrap_text("the quick brown fox", 10));
+        assert_eq!(hard_wrap("abcdef gh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(hard_wrap("abc", 0), vec!["a", "b", "c"]);
+        assert!(hard_wrap("", 5).is_empty());
+        assert!(hard_wrap("ééééééé xx", 3).iter().all(|line| line.chars().count() <= 3));
+    }
+
+    #[test]
+    fn test_is_valid_email() {
+        assert!(is_valid_email("user@example.com"));
+        assert!(is_valid_email("first.last+tag@mail.example.co.uk"));
+        assert!(is_valid_email("a@b.c"));
+
+        assert!(!is_valid_email("foo@"));
+        assert!(!is_valid_email("@bar.com"));
+        assert!(!is_valid_email("no-at-sign"));
+        assert!(!is_valid_email("two@@example.com"));
+        assert!(!is_valid_email("a@b@example.com"));
+        assert!(!is_valid_email("user@localhost"));
+        assert!(!is_valid_email("user@.com"));
+        assert!(!is_valid_email("user@example."));
+        assert!(!is_valid_email("user@example..com"));
+        assert!(!is_valid_email("john doe@example.com"));
+        assert!(!is_valid_email(""));
+    }
+
+    #[test]
+    fn test_parse_ipv4() {
+        assert_eq!(parse_ipv4("192.168.1.1"), Some([192, 168, 1, 1]));
+        assert_eq!(parse_ipv4("0.0.0.0"), Some([0, 0, 0, 0]));
+        assert_eq!(parse_ipv4("255.255.255.255"), Some([255, 255, 255, 255]));
+        assert_eq!(parse_ipv4("10.0.100.9"), Some([10, 0, 100, 9]));
+    }
+
+    #[test]
+    fn test_is_valid_ipv4() {
+        assert!(is_valid_ipv4("127.0.0.1"));
+        assert!(is_valid_ipv4("255.0.0.0"));
+
+        assert!(!is_valid_ipv4("256.1.1.1"));
+        assert!(!is_valid_ipv4("1.1.1.256"));
+        assert!(!is_valid_ipv4("1.2.3"));
+        assert!(!is_valid_ipv4("1.2.3.4.5"));
+        assert!(!is_valid_ipv4("1.2.3."));
+        assert!(!is_valid_ipv4("1..2.3"));
+        assert!(!is_valid_ipv4("01.2.3.4"));
+        assert!(!is_valid_ipv4("1.2.3.00"));
+        assert!(!is_valid_ipv4("1.2.3.0004"));
+        assert!(!is_valid_ipv4("+1.2.3.4"));
+        assert!(!is_valid_ipv4("1.2.3.a"));
+        assert!(!is_valid_ipv4(" 1.2.3.4"));
+        assert!(!is_valid_ipv4(""));
+    }
+
+    #[test]
+    fn test_to_title_case_with_acronyms() {
+        let acronyms = ["nasa", "faq", "URL"];
+        assert_eq!(to_title_case_with_acronyms("the nasa faq", &acronyms), "The NASA FAQ");
+        assert_eq!(to_title_case_with_acronyms("NaSa and the url", &acronyms), "NASA And The URL");
+        assert_eq!(to_title_case_with_acronyms("read the faq, then ask", &acronyms), "Read The FAQ, Then Ask");
+        // Acronyms only match whole words
+        assert_eq!(to_title_case_with_acronyms("faqs about nasal sprays", &acronyms), "Faqs About Nasal Sprays");
+        assert_eq!(to_title_case_with_acronyms("hello world", &[]), to_title_case("hello world"));
+    }
+
+    #[test]
+    fn test_tokenize_strip_punctuation() {
+        assert_eq!(tokenize("Hello, world!", false), vec!["Hello", "world"]);
+        assert_eq!(
+            tokenize("\"Don't panic,\" she said -- (twice).", false),
+            vec!["Don't", "panic", "she", "said", "twice"]
+        );
+        assert_eq!(tokenize("«Bonjour» … ¿qué?", false), vec!["Bonjour", "qué"]);
+        assert!(tokenize("  ... !!! ", false).is_empty());
+    }
+
+    #[test]
+    fn test_tokenize_keep_punctuation() {
+        assert_eq!(tokenize("Hello, world!", true), vec!["Hello,", "world!"]);
+        assert_eq!(tokenize("a -- b", true), vec!["a", "--", "b"]);
+        assert!(tokenize("   ", true).is_empty());
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("file", 0), "files");
+        assert_eq!(pluralize("file", 2), "files");
+        assert_eq!(pluralize("box", 5), "boxes");
+        assert_eq!(pluralize("bus", 2), "buses");
+        assert_eq!(pluralize("match", 2), "matches");
+        assert_eq!(pluralize("wish", 2), "wishes");
+        assert_eq!(pluralize("berry", 2), "berries");
+        assert_eq!(pluralize("day", 2), "days");
+        assert_eq!(pluralize("y", 2), "ys");
+    }
+
+    #[test]
+    fn test_pluralize_singular() {
+        assert_eq!(pluralize("file", 1), "file");
+        assert_eq!(pluralize("box", 1), "box");
+        assert_eq!(pluralize("berry", 1), "berry");
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        // Long form
+        assert_eq!(parse_hex_color("#ff0000"), Some((255, 0, 0)));
+        assert_eq!(parse_hex_color("#1E90FF"), Some((30, 144, 255)));
+        assert_eq!(parse_hex_color("#000000"), Some((0, 0, 0)));
+        // Short form
+        assert_eq!(parse_hex_color("#f00"), Some((255, 0, 0)));
+        assert_eq!(parse_hex_color("#ABC"), Some((170, 187, 204)));
+        // Missing hash
+        assert_eq!(parse_hex_color("ffffff"), Some((255, 255, 255)));
+        assert_eq!(parse_hex_color("0f0"), Some((0, 255, 0)));
+    }
+
+    #[test]
+    fn test_parse_hex_color_invalid() {
+        assert_eq!(parse_hex_color(""), None);
+        assert_eq!(parse_hex_color("#"), None);
+        assert_eq!(parse_hex_color("#ff00"), None);
+        assert_eq!(parse_hex_color("#ff00000"), None);
+        assert_eq!(parse_hex_color("#gg0000"), None);
+        assert_eq!(parse_hex_color("##ff0000"), None);
+        assert_eq!(parse_hex_color("#+f0000"), None);
+        assert_eq!(parse_hex_color("#ff 000"), None);
+        assert_eq!(parse_hex_color("#éé00"), None);
+        assert!(!is_valid_hex_color("red"));
+        assert!(is_valid_hex_color("#fff"));
+    }
+
+    #[test]
+    fn test_trim_prefix_all() {
+        assert_eq!(trim_prefix_all("xxxxdata", "xx"), "data");
+        assert_eq!(trim_prefix_all("xxxdata", "xx"), "xdata");
+        assert_eq!(trim_prefix_all("../../../etc", "../"), "etc");
+        assert_eq!(trim_prefix_all("data", "xx"), "data");
+        assert_eq!(trim_prefix_all("xxxx", "xx"), "");
+        // Empty prefix guard
+        assert_eq!(trim_prefix_all("data", ""), "data");
+        assert_eq!(trim_prefix_all("", ""), "");
+    }
+
+    #[test]
+    fn test_trim_suffix_all() {
+        assert_eq!(trim_suffix_all("data!!!!", "!!"), "data");
+        assert_eq!(trim_suffix_all("data!!!", "!!"), "data!");
+        assert_eq!(trim_suffix_all("report.bak.bak", ".bak"), "report");
+        assert_eq!(trim_suffix_all("data", "xx"), "data");
+        // Empty suffix guard
+        assert_eq!(trim_suffix_all("data", ""), "data");
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+        assert_eq!(levenshtein("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        // Transpositions cost one edit instead of two
+        assert_eq!(levenshtein("ca", "ac"), 2);
+        assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+        assert_eq!(levenshtein("recieve", "receive"), 2);
+        assert_eq!(damerau_levenshtein("recieve", "receive"), 1);
+        // Optimal string alignment: the transposed pair isn't edited again
+        assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+        // Otherwise it agrees with plain Levenshtein
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+        assert_eq!(damerau_levenshtein("", "abc"), 3);
+        assert_eq!(damerau_levenshtein("same", "same"), 0);
+        assert_eq!(damerau_levenshtein("éa", "aé"), 1);
+    }
+
+    #[test]
+    fn test_can_form() {
+        // Sufficient pool
+        assert!(can_form("cat", "atcx"));
+        assert!(can_form("Cat", "TACO"));
+        assert!(can_form("", "abc"));
+        // Insufficient pool
+        assert!(!can_form("cat", "ca"));
+        assert!(!can_form("book", "bok"));
+        assert!(!can_form("a", ""));
+        // Exact pool
+        assert!(can_form("listen", "silent"));
+        assert!(can_form("book", "obko"));
+    }
+
+    #[test]
+    fn test_normalize_whitespace() {
+        assert_eq!(normalize_whitespace("  hello   world  "), "hello world");
+        assert_eq!(normalize_whitespace("tab\tseparated\t\tvalues"), "tab separated values");
+        assert_eq!(normalize_whitespace("line one\r\n\n  line two\n"), "line one line two");
+        assert_eq!(normalize_whitespace("already normal"), "already normal");
+        assert_eq!(normalize_whitespace(" \t\n "), "");
+        assert_eq!(normalize_whitespace(""), "");
+    }
+
+    #[test]
+    fn test_unicode_word_count() {
+        assert_eq!(unicode_word_count("can't stop"), 2);
+        assert_eq!(unicode_word_count("hello world"), word_count("hello world"));
+        // Punctuation-only tokens aren't words
+        assert_eq!(word_count("wait -- what ?"), 4);
+        assert_eq!(unicode_word_count("wait -- what ?"), 2);
+        // Hyphenated words count as their parts
+        assert_eq!(word_count("a well-known fact"), 3);
+        assert_eq!(unicode_word_count("a well-known fact"), 4);
+        // Scripts without spaces
+        assert_eq!(word_count("我喜欢Rust"), 1);
+        assert_eq!(unicode_word_count("我喜欢Rust"), 4);
+        assert_eq!(unicode_word_count(""), 0);
+        assert_eq!(unicode_word_count("  ...  "), 0);
+    }
+
+    #[test]
+    fn test_count_char_ignore_case() {
+        assert_eq!(count_char_ignore_case("LLlama", 'l'), 3);
+        assert_eq!(count_char_ignore_case("LLlama", 'L'), 3);
+        assert_eq!(count_char_ignore_case("hello world", 'z'), 0);
+        assert_eq!(count_char_ignore_case("", 'a'), 0);
+        // Non-ASCII letters
+        assert_eq!(count_char_ignore_case("Ébène été", 'é'), 3);
+        assert_eq!(count_char_ignore_case("Straße STRAẞE", 'ß'), 2);
+        assert_eq!(count_char_ignore_case("ΣΊΣΥΦΟΣ σίσυφος", 'σ'), 6);
+        // Case-insensitivity doesn't conflate different letters
+        assert_eq!(count_char_ignore_case("Ébène", 'e'), 1);
+    }
+
+    #[test]
+    fn test_similarity_ratio() {
+        // Identical
+        assert_eq!(similarity_ratio("hello", "hello"), 1.0);
+        assert_eq!(similarity_ratio("", ""), 1.0);
+        // Completely different, same length
+        assert_eq!(similarity_ratio("abc", "xyz"), 0.0);
+        assert_eq!(similarity_ratio("", "abc"), 0.0);
+        // Partial matches
+        assert_eq!(similarity_ratio("rust", "bust"), 0.75);
+        assert_eq!(similarity_ratio("kitten", "sitting"), 1.0 - 3.0 / 7.0);
+        assert_eq!(similarity_ratio("café", "cafe"), 0.75);
+    }
+
+    #[test]
+    fn test_strip_accents() {
+        assert_eq!(strip_accents("Crème brûlée"), "Creme brulee");
+        assert_eq!(strip_accents("ŁÓDŹ"), "LODZ");
+        assert_eq!(strip_accents("cafe\u{301}"), "cafe");
+        assert_eq!(strip_accents("plain"), "plain");
+        assert_eq!(strip_accents("日本"), "日本");
+    }
+
+    #[test]
+    fn test_is_ascii() {
+        assert!(is_ascii(""));
+        assert!(is_ascii("Hello, World! 123"));
+        assert!(!is_ascii("Mötley Crüe"));
+        assert!(!is_ascii("🦀"));
+    }
+
+    #[test]
+    fn test_to_ascii_safe() {
+        // Pure ASCII is unchanged
+        assert_eq!(to_ascii_safe("Hello, World!"), "Hello, World!");
+        // Accented Latin
+        assert_eq!(to_ascii_safe("Mötley Crüe"), "Motley Crue");
+        assert_eq!(to_ascii_safe("Zoë Saldaña"), "Zoe Saldana");
+        // Remaining non-ASCII is dropped
+        assert_eq!(to_ascii_safe("I 🦀 Rust"), "I  Rust");
+        assert_eq!(to_ascii_safe("日本"), "");
+    }
+
+    #[test]
+    fn test_to_superscript() {
+        assert_eq!(to_superscript(0), "⁰");
+        assert_eq!(to_superscript(23), "²³");
+        assert_eq!(to_superscript(1234567890), "¹²³⁴⁵⁶⁷⁸⁹⁰");
+    }
+
+    #[test]
+    fn test_to_subscript() {
+        assert_eq!(to_subscript(0), "₀");
+        assert_eq!(to_subscript(23), "₂₃");
+        assert_eq!(to_subscript(1234567890), "₁₂₃₄₅₆₇₈₉₀");
+    }
+
+    #[test]
+    fn test_caesar_shift() {
+        assert_eq!(caesar_shift("abc xyz", 3), "def abc");
+        assert_eq!(caesar_shift("ABC", -1), "ZAB");
+        assert_eq!(caesar_shift("Hello, World!", 26), "Hello, World!");
+        assert_eq!(caesar_shift("Hello, World!", 55), caesar_shift("Hello, World!", 3));
+        assert_eq!(caesar_shift("Café 123", 1), "Dbgé 123");
+    }
+
+    #[test]
+    fn test_caesar_crack() {
+        let plaintext = "The quick brown fox jumps over the lazy dog while the farmer sleeps.";
+        for shift in [0, 1, 13, 25] {
+            let ciphertext = caesar_shift(plaintext, shift);
+            assert_eq!(caesar_crack(&ciphertext), (shift, plaintext.to_string()));
+        }
+        assert_eq!(caesar_crack("123 !?"), (0, "123 !?".to_string()));
+        assert_eq!(caesar_crack(""), (0, String::new()));
     }
 }
\ No newline at end of file
@@ -0,0 +1,205 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+fn cli_utils(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cli-utils"))
+        .args(args)
+        .output()
+        .expect("Failed to run cli-utils")
+}
+
+fn stdout_of(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_date_diff_integration() {
+    let output = cli_utils(&["date", "diff", "2023-01-10", "2023-01-05"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "5\n");
+
+    let output = cli_utils(&["date", "diff", "2023-01-05", "2023-01-10"]);
+    assert_eq!(stdout_of(&output), "-5\n");
+}
+
+#[test]
+fn test_date_add_integration() {
+    let output = cli_utils(&["date", "add", "2023-12-25", "7"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "2024-01-01\n");
+
+    let output = cli_utils(&["date", "add", "2023-12-25", "-5"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "2023-12-20\n");
+}
+
+#[test]
+fn test_date_weekday_integration() {
+    let output = cli_utils(&["date", "weekday", "2023-12-25"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "Monday\n");
+}
+
+#[test]
+fn test_date_leap_integration() {
+    let output = cli_utils(&["date", "leap", "2024"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "true\n");
+
+    assert_eq!(stdout_of(&cli_utils(&["date", "leap", "1900"])), "false\n");
+}
+
+#[test]
+fn test_date_invalid_args_integration() {
+    let output = cli_utils(&["date", "diff", "2023-02-30", "2023-01-01"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected a valid date in YYYY-MM-DD format"));
+
+    assert!(!cli_utils(&["date", "weekday", "25/12/2023"]).status.success());
+    assert!(!cli_utils(&["date", "add", "2023-12-25", "seven"]).status.success());
+    assert!(!cli_utils(&["date", "leap", "abc"]).status.success());
+
+    let output = cli_utils(&["date", "add", "2023-12-25", "99999999999"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error:"));
+}
+
+#[test]
+fn test_file_stats_integration() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/stats.txt");
+    let output = cli_utils(&["file", "stats", fixture]);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_of(&output),
+        "Lines: 3\nWords: 16\nCharacters: 75\nTop words:\n  the: 4\n  dog: 2\n  fox: 2\n  and: 1\n  barks: 1\n"
+    );
+}
+
+#[test]
+fn test_file_stats_missing_file_integration() {
+    let output = cli_utils(&["file", "stats", "does/not/exist.txt"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error:"));
+}
+
+#[test]
+fn test_math_gcd_integration() {
+    let output = cli_utils(&["math", "gcd", "48", "18"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "6\n");
+}
+
+#[test]
+fn test_math_lcm_integration() {
+    let output = cli_utils(&["math", "lcm", "4", "6"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "12\n");
+}
+
+#[test]
+fn test_math_primes_integration() {
+    let output = cli_utils(&["math", "primes", "30"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "2 3 5 7 11 13 17 19 23 29\n");
+
+    let output = cli_utils(&["math", "primes", "100"]);
+    assert_eq!(stdout_of(&output).split_whitespace().count(), 25);
+}
+
+#[test]
+fn test_math_invalid_args_integration() {
+    assert!(!cli_utils(&["math", "gcd", "48", "abc"]).status.success());
+    assert!(!cli_utils(&["math", "lcm", "4"]).status.success());
+    assert!(!cli_utils(&["math", "primes", "-5"]).status.success());
+}
+
+#[test]
+fn test_math_out_of_range_integration() {
+    let output = cli_utils(&["math", "lcm", "4294967296", "4294967297"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error:"));
+
+    for limit in ["10000001", "18446744073709551615"] {
+        let output = cli_utils(&["math", "primes", limit]);
+        assert_eq!(output.status.code(), Some(1));
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error:"));
+    }
+
+    let output = cli_utils(&["math", "lcm", "4294967296", "2"]);
+    assert_eq!(stdout_of(&output), "4294967296\n");
+}
+
+#[test]
+fn test_string_reverse_integration() {
+    let output = cli_utils(&["string", "reverse", "hello"]);
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "olleh\n");
+}
+
+#[test]
+fn test_string_reverse_stdin_integration() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cli-utils"))
+        .args(["string", "reverse", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run cli-utils");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"hello\nrust programming\n\nabc")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "olleh\ngnimmargorp tsur\n\ncba\n");
+}
+
+#[test]
+fn test_watch_reruns_on_change_integration() {
+    let watched = std::env::temp_dir().join(format!("cli_utils_watch_{}.txt", std::process::id()));
+    fs::write(&watched, "initial").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cli-utils"))
+        .arg("watch")
+        .arg(&watched)
+        .args(["--", "string", "reverse", "abc"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run cli-utils");
+
+    // Touch the file twice, leaving time for the watcher to poll in between
+    for offset in [60, 120] {
+        sleep(Duration::from_millis(1200));
+        File::options()
+            .write(true)
+            .open(&watched)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(offset))
+            .unwrap();
+    }
+    sleep(Duration::from_millis(1200));
+    child.kill().unwrap();
+    let output = child.wait_with_output().unwrap();
+    let _ = fs::remove_file(&watched);
+
+    // One run at startup plus one per change; allow for a slow startup
+    // having already seen the first change
+    let stdout = stdout_of(&output);
+    let runs: Vec<&str> = stdout.lines().collect();
+    assert!(runs.len() >= 2, "expected repeated runs, got {:?}", stdout);
+    assert!(runs.iter().all(|line| *line == "cba"));
+}
+
+#[test]
+fn test_watch_invalid_subcommand_integration() {
+    let output = cli_utils(&["watch", "Cargo.toml", "--", "math", "nope"]);
+    assert!(!output.status.success());
+
+    let output = cli_utils(&["watch", "does/not/exist.txt", "--", "math", "gcd", "4", "6"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error:"));
+}
@@ -9,10 +9,10 @@ fn test_date_difference_integration() {
 
 #[test]
 fn test_date_validation_integration() {
-    assert_eq!(validate_date_format("2023-12-25", "%Y-%m-%d"), true);
-    assert_eq!(validate_date_format("25/12/2023", "%d/%m/%Y"), true);
-    assert_eq!(validate_date_format("2023-13-25", "%Y-%m-%d"), false);
-    assert_eq!(validate_date_format("invalid-date", "%Y-%m-%d"), false);
+    assert!(validate_date_format("2023-12-25", "%Y-%m-%d"));
+    assert!(validate_date_format("25/12/2023", "%d/%m/%Y"));
+    assert!(!validate_date_format("2023-13-25", "%Y-%m-%d"));
+    assert!(!validate_date_format("invalid-date", "%Y-%m-%d"));
 }
 
 #[test]
@@ -36,10 +36,10 @@ fn test_date_arithmetic_integration() {
 
 #[test]
 fn test_leap_year_integration() {
-    assert_eq!(is_leap_year(2024), true);
-    assert_eq!(is_leap_year(2023), false);
-    assert_eq!(is_leap_year(2000), true);
-    assert_eq!(is_leap_year(1900), false);
+    assert!(is_leap_year(2024));
+    assert!(!is_leap_year(2023));
+    assert!(is_leap_year(2000));
+    assert!(!is_leap_year(1900));
 }
 
 #[test]
@@ -140,4 +140,25 @@ fn test_directory_operations_integration() {
     
     // Clean up
     let _ = fs::remove_dir(&test_dir);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_file_counts_matches_wc() {
+    // `wc tests/fixtures/wc.txt` reports 4 lines, 16 words and 100 bytes
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/wc.txt");
+    assert_eq!(file_counts(fixture).unwrap(), (4, 16, 100));
+    assert_eq!(file_counts(fixture).unwrap().2 as u64, file_size(fixture).unwrap());
+}
+
+#[test]
+fn test_most_frequent_word_fixture() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/stats.txt");
+    assert_eq!(most_frequent_word(fixture).unwrap(), Some(("the".to_string(), 4)));
+}
+
+#[test]
+fn test_line_length_stats_fixture() {
+    // Lines of 20, 0, 24, 23 ("Café crème brûlée" is counted in chars, not bytes) and 25
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/wc.txt");
+    assert_eq!(line_length_stats(fixture).unwrap(), (0, 25, 18.4));
+}
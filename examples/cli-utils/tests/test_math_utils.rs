@@ -17,11 +17,11 @@ fn test_gcd_integration() {
 
 #[test]
 fn test_prime_integration() {
-    assert_eq!(is_prime(2), true);
-    assert_eq!(is_prime(17), true);
-    assert_eq!(is_prime(97), true);
-    assert_eq!(is_prime(4), false);
-    assert_eq!(is_prime(100), false);
+    assert!(is_prime(2));
+    assert!(is_prime(17));
+    assert!(is_prime(97));
+    assert!(!is_prime(4));
+    assert!(!is_prime(100));
 }
 
 #[test]
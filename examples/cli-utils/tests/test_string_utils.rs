@@ -2,10 +2,10 @@ use cli_utils::string_utils::*;
 
 #[test]
 fn test_palindrome_integration() {
-    assert_eq!(is_palindrome("racecar"), true);
-    assert_eq!(is_palindrome("A man a plan a canal Panama"), true);
-    assert_eq!(is_palindrome("hello"), false);
-    assert_eq!(is_palindrome("Was it a car or a cat I saw"), true);
+    assert!(is_palindrome("racecar"));
+    assert!(is_palindrome("A man a plan a canal Panama"));
+    assert!(!is_palindrome("hello"));
+    assert!(is_palindrome("Was it a car or a cat I saw"));
 }
 
 #[test]
@@ -33,6 +33,6 @@ fn test_title_case_integration() {
 fn test_utility_functions_integration() {
     assert_eq!(remove_whitespace("hello world test"), "helloworldtest");
     assert_eq!(word_count("hello world programming"), 3);
-    assert_eq!(is_alphabetic("HelloWorld"), true);
-    assert_eq!(is_alphabetic("Hello123"), false);
+    assert!(is_alphabetic("HelloWorld"));
+    assert!(!is_alphabetic("Hello123"));
 }
\ No newline at end of file